@@ -27,18 +27,243 @@ fn name(s: &str) -> Ident {
     Ident::new(&format!("{}{}", s, suffix), Span::call_site())
 }
 
+/// The `#[cfg(..)]` attributes of a source item (a trait, an impl, or one of their members),
+/// repeated on every item derived from it so the derived items come and go with it.
+fn cfg_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("cfg")).collect()
+}
+
+/// A copy of an impl's method that `finalize` emits, for [`method_attrs`].
+#[derive(Clone, Copy, PartialEq)]
+enum AttrSite {
+    /// The inductive copy, which compiles the user's body.
+    Body,
+    /// The Final impl's delegating method, the one callers outside the module see.
+    Final,
+    /// The leaf's floor.
+    Floor,
+}
+
+/// The attributes of an impl's method that go on its copy at `site` (the crate docs'
+/// "Attributes" table). `cfg` and `track_caller` go everywhere, so a location is tracked from
+/// the caller's call site down to the floor; `inline`, `cold` and `doc` go on the body and the
+/// delegating method; `must_use` and `deprecated` on the delegating method only. Lint levels,
+/// and any other attribute, stay with the body, where the lints they govern fire.
+fn method_attrs(attrs: &[Attribute], site: AttrSite) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            let path = attr.path();
+            if path.is_ident("cfg") || path.is_ident("track_caller") {
+                true
+            } else if path.is_ident("inline") || path.is_ident("cold") || path.is_ident("doc") {
+                site != AttrSite::Floor
+            } else if path.is_ident("must_use") || path.is_ident("deprecated") {
+                site == AttrSite::Final
+            } else {
+                site == AttrSite::Body
+            }
+        })
+        .collect()
+}
+
+/// Rewrites a method's `must_use` to name `trait_ident::method` in its message: a call in a
+/// cycle body resolves to the ranked trait (or a dyn entry), which is what the lint names.
+fn name_must_use(attrs: &mut [Attribute], trait_ident: &Ident, method: &Ident) {
+    for attr in attrs.iter_mut().filter(|attr| attr.path().is_ident("must_use")) {
+        let origin = format!("returned by `{}::{}`", trait_ident, method);
+        let message = match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value: Expr::Lit(ExprLit { lit: Lit::Str(reason), .. }),
+                ..
+            }) => format!("{}: {}", origin, reason.value()),
+            _ => origin,
+        };
+        let message = LitStr::new(&message, syn::spanned::Spanned::span(attr.path()));
+        attr.meta = parse_quote!(must_use = #message);
+    }
+}
+
+/// `#[track_caller]` for a method decycle adds in front of `sig`'s (a dyn entry, a forwarding
+/// method), so the caller's location is passed on — unless `sig` has a non-Rust ABI, which the
+/// attribute doesn't support (E0737).
+fn track_caller_attr(sig: &Signature) -> Option<TokenStream> {
+    let rust_abi = sig
+        .abi
+        .as_ref()
+        .map_or(true, |abi| abi.name.as_ref().is_some_and(|name| name.value() == "Rust"));
+    rust_abi.then(|| quote!(#[track_caller]))
+}
+
+/// `#[decycle(erase)]`, which `process_module` leaves on an impl for its floor to re-enter
+/// through `Erase` (`emit_impl_items_leaf`). Not emitted on any copy.
+fn is_erase_marker(attr: &Attribute) -> bool {
+    attr.path().is_ident("decycle")
+        && attr.parse_args::<Ident>().is_ok_and(|arg| arg == "erase")
+}
+
+/// Whether a `#[decycle(erase)]` impl's floor can call `sig` on the erased value: a
+/// non-generic `&self` or `self` method with no other `Self` in its signature (the erased
+/// value's `Self` is another type).
+fn is_erasable(sig: &Signature) -> bool {
+    fn mentions_self(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(ident) => ident == "Self",
+            proc_macro2::TokenTree::Group(group) => mentions_self(group.stream()),
+            _ => false,
+        })
+    }
+    let receiver_ok = sig.receiver().is_some_and(|receiver| {
+        receiver.colon_token.is_none()
+            && (receiver.reference.is_none() || receiver.mutability.is_none())
+    });
+    let output = &sig.output;
+    receiver_ok
+        && sig.generics.params.is_empty()
+        && !sig
+            .inputs
+            .iter()
+            .skip(1)
+            .any(|input| mentions_self(quote!(#input)))
+        && !mentions_self(quote!(#output))
+}
+
+/// A type param of a trait method named in its `#[decycle(erase(S => dyn Stream))]`: taken
+/// only as `&S` or `&mut S`, it re-enters from the floor as `&dyn Stream` / `&mut dyn Stream`.
+struct ErasedParam {
+    object: Type,
+    mutability: Option<Token![mut]>,
+}
+
+impl ErasedParam {
+    /// The type the method is instantiated with past the floor.
+    fn arg(&self) -> Type {
+        let object = &self.object;
+        let mutability = &self.mutability;
+        parse_quote!(&#mutability (#object))
+    }
+}
+
+/// The erased type params of a trait method, in declaration order, or none without
+/// `#[decycle(erase(..))]`. Erasing is all or nothing: every type param must be named, so
+/// the method has the one re-entry whatever it's called with (`reentry_margs`).
+fn erased_method_params(tf: &TraitItemFn) -> Vec<ErasedParam> {
+    syn::custom_keyword!(erase);
+    let mut pairs: Vec<(Ident, Type)> = Vec::new();
+    for attr in tf.attrs.iter().filter(|attr| attr.path().is_ident("decycle")) {
+        attr.parse_args_with(|input: ParseStream| {
+            input.parse::<erase>()?;
+            let content;
+            parenthesized!(content in input);
+            let parsed = content.parse_terminated(
+                |input: ParseStream| {
+                    let ident: Ident = input.parse()?;
+                    input.parse::<Token![=>]>()?;
+                    Ok((ident, input.parse::<Type>()?))
+                },
+                Token![,],
+            )?;
+            pairs.extend(parsed);
+            Ok(())
+        })
+        .unwrap_or_else(|e| {
+            abort!(
+                e.span(), "{}", e;
+                hint = "a #[decycle] trait's method accepts `#[decycle(erase(S => dyn Trait, ...))]`"
+            )
+        });
+    }
+    if pairs.is_empty() {
+        return Vec::new();
+    }
+    let sig = &tf.sig;
+    if let Some(param) = sig.generics.params.iter().find(|p| match p {
+        GenericParam::Type(tp) => !pairs.iter().any(|(ident, _)| ident == &tp.ident),
+        GenericParam::Const(_) => true,
+        GenericParam::Lifetime(_) => false,
+    }) {
+        abort!(
+            param,
+            "decycle: every type parameter of `{}` must be erased to re-enter it",
+            sig.ident
+        );
+    }
+    let mentions = |ty: &Type, ident: &Ident| type_mentions_idents(ty, &[ident.clone()].into());
+    pairs
+        .into_iter()
+        .map(|(ident, object)| {
+            if !sig.generics.type_params().any(|tp| tp.ident == ident) {
+                abort!(ident, "decycle: `{}` is not a type parameter of `{}`", ident, sig.ident);
+            }
+            if !matches!(object, Type::TraitObject(_)) {
+                abort!(object, "decycle: `{}` must be erased to a `dyn` trait object", ident);
+            }
+            let mut mutability = None;
+            let mut shared = false;
+            for input in &sig.inputs {
+                let FnArg::Typed(PatType { ty, .. }) = input else { continue };
+                match &**ty {
+                    Type::Reference(r)
+                        if matches!(&*r.elem, Type::Path(p) if p.qself.is_none() && p.path.is_ident(&ident)) =>
+                    {
+                        shared |= r.mutability.is_none();
+                        mutability = mutability.or(r.mutability);
+                    }
+                    ty if mentions(ty, &ident) => abort!(
+                        ty,
+                        "decycle: an erased `{}` can only be taken as `&{}` or `&mut {}`",
+                        ident, ident, ident
+                    ),
+                    _ => {}
+                }
+            }
+            if let ReturnType::Type(_, ty) = &sig.output {
+                if mentions(ty, &ident) {
+                    abort!(ty, "decycle: an erased `{}` can't appear in the return type", ident);
+                }
+            }
+            if shared && mutability.is_some() {
+                abort!(ident, "decycle: an erased `{}` must be taken as either `&{}` or `&mut {}`, not both", ident, ident, ident);
+            }
+            if !shared {
+                mutability = Some(Default::default());
+            }
+            ErasedParam { object, mutability }
+        })
+        .collect()
+}
+
+/// The method generics `tf`'s floors are keyed on whatever it's called with: none for a
+/// non-generic method, its erased types for an erased one, and `None` for any other generic
+/// method, whose key is its own instantiation.
+fn reentry_margs(tf: &TraitItemFn) -> Option<Vec<GenericArgument>> {
+    let erased = erased_method_params(tf);
+    if !erased.is_empty() {
+        Some(erased.iter().map(|p| GenericArgument::Type(p.arg())).collect())
+    } else if method_is_generic(&tf.sig) {
+        None
+    } else {
+        Some(Vec::new())
+    }
+}
+
 /// Rewrites single-segment trait paths that match the replacing table to their
 /// ranked equivalents. E.g., `Evaluate` → `ranked_traits::EvaluateRanked<Rank, ()>`.
 ///
 /// Applied twice per inductive impl:
 /// 1. With `rank_type = (Rank,)` on the impl's trait path only
 /// 2. With `rank_type = Rank` on the entire impl (where clause + body)
+#[derive(Clone)]
 struct TraitReplacer {
     /// Maps original trait ident (e.g., "Evaluate") to (rank_loc, ranked_path)
     /// where ranked_path is a multi-segment path like `ranked_traits::EvaluateRanked`
     table: HashMap<Ident, (usize, Path)>,
     /// The rank type to insert at rank_loc
     rank_type: Type,
+    /// Maps a trait with associated types to their idents and its rank-free types trait
+    /// (`ranked_traits::EvaluateTypes`). A `<T as Evaluate>::Output` projection is rewritten
+    /// to that path instead of the ranked one, so it names the same type at every rank.
+    types_table: HashMap<Ident, (Vec<Ident>, Path)>,
 }
 
 impl TraitReplacer {
@@ -56,8 +281,21 @@ impl TraitReplacer {
         let Some((rank_loc, replacement)) = self.table.get(&path.segments[offset].ident) else {
             return false;
         };
-        let orig_args =
+        let mut orig_args =
             std::mem::replace(&mut path.segments[offset].arguments, PathArguments::None);
+        if self.types_table.contains_key(&path.segments[offset].ident) {
+            // `Output = X` constraints live on the types trait, which the ranked one doesn't
+            // declare — `types_where_predicates` / `constrained_types_predicates` carry them
+            // on a `T: TraitTypes<..>` bound.
+            if let PathArguments::AngleBracketed(angle_args) = &mut orig_args {
+                angle_args.args = std::mem::take(&mut angle_args.args)
+                    .into_iter()
+                    .filter(|arg| {
+                        !matches!(arg, GenericArgument::AssocType(_) | GenericArgument::Constraint(_))
+                    })
+                    .collect();
+            }
+        }
         let mut new_path = replacement.clone();
         new_path.segments.last_mut().unwrap().arguments = orig_args;
         path_insert_type_arg(&mut new_path, *rank_loc, self.rank_type.clone());
@@ -81,6 +319,29 @@ impl TraitReplacer {
             // Check if the first (post-`self::`) segment is a trait in our table.
             if qs.position > offset && qs.position <= path.segments.len() {
                 let first_ident = &path.segments[offset].ident;
+                if let Some((assoc_idents, types_path)) = self.types_table.get(first_ident) {
+                    if qs.position == offset + 1
+                        && path
+                            .segments
+                            .get(offset + 1)
+                            .is_some_and(|seg| assoc_idents.contains(&seg.ident))
+                    {
+                        // `<T as Trait>::Assoc` -> `<T as ranked_traits::TraitTypes>::Assoc`:
+                        // no rank argument, the types trait is shared by every rank.
+                        let orig_args = std::mem::replace(
+                            &mut path.segments[offset].arguments,
+                            PathArguments::None,
+                        );
+                        let mut new_segments = types_path.segments.clone();
+                        new_segments.last_mut().unwrap().arguments = orig_args;
+                        for seg in path.segments.iter().skip(offset + 1) {
+                            new_segments.push(seg.clone());
+                        }
+                        qs.position = types_path.segments.len();
+                        path.segments = new_segments;
+                        return true;
+                    }
+                }
                 if let Some((rank_loc, replacement)) = self.table.get(first_ident) {
                     let orig_args =
                         std::mem::replace(&mut path.segments[offset].arguments, PathArguments::None);
@@ -148,6 +409,69 @@ impl syn::visit_mut::VisitMut for TraitReplacer {
             syn::visit_mut::visit_type_path_mut(self, type_path);
         }
     }
+
+    fn visit_type_trait_object_mut(&mut self, object: &mut TypeTraitObject) {
+        qualify_trait_object(object, &self.table);
+        syn::visit_mut::visit_type_trait_object_mut(self, object);
+    }
+}
+
+/// Spells each `#[decycle]` trait of a `dyn Trait` through its `object_trait_name` re-export, at
+/// the location of the ranked path `table` gives for it (`ranked_traits::XRanked` ⇒
+/// `ranked_traits::trait_objects::XObject`): the trait object keeps the original trait.
+fn qualify_trait_object(object: &mut TypeTraitObject, table: &HashMap<Ident, (usize, Path)>) {
+    for bound in &mut object.bounds {
+        let TypeParamBound::Trait(bound) = bound else {
+            continue;
+        };
+        let offset = self_offset(&bound.path);
+        if bound.path.segments.len() != offset + 1 {
+            continue;
+        }
+        let seg = bound.path.segments[offset].clone();
+        let Some((_, ranked)) = table.get(&seg.ident) else {
+            continue;
+        };
+        let mut path = ranked.clone();
+        path.segments.pop();
+        path.segments.push(PathSegment::from(trait_objects_module_name()));
+        path.segments.push(PathSegment {
+            ident: object_trait_name(&seg.ident),
+            arguments: seg.arguments,
+        });
+        bound.path = path;
+    }
+}
+
+/// `qualify_trait_object` on generated tokens: `dyn Trait` ⇒ `dyn ranked_traits::trait_objects::
+/// XObject`, for the items `finalize` emits inside `shadowing_module` without ranking them (the
+/// registrations, built from the user's impl).
+fn qualify_trait_object_tokens(tokens: TokenStream, table: &HashMap<Ident, (usize, Path)>) -> TokenStream {
+    use proc_macro2::{Group, TokenTree};
+    let mut out = Vec::new();
+    let mut after_dyn = false;
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) => {
+                let mut new = Group::new(group.delimiter(), qualify_trait_object_tokens(group.stream(), table));
+                new.set_span(group.span());
+                out.push(TokenTree::Group(new));
+                after_dyn = false;
+            }
+            TokenTree::Ident(ident) if after_dyn && table.contains_key(&ident) => {
+                let mut object: TypeTraitObject = parse_quote!(dyn #ident);
+                qualify_trait_object(&mut object, table);
+                let bound = &object.bounds;
+                out.extend(quote!(#bound));
+                after_dyn = false;
+            }
+            tt => {
+                after_dyn = matches!(&tt, TokenTree::Ident(ident) if ident == "dyn");
+                out.push(tt);
+            }
+        }
+    }
+    out.into_iter().collect()
 }
 
 fn remove_cyclic_bounds(
@@ -175,6 +499,126 @@ fn remove_cyclic_bounds(
     g
 }
 
+/// The generics of an impl of a trait's rank-free types trait (`ranked_types_trait_name`):
+/// `remove_cyclic_bounds`, plus `T: XTypes<..>` for every cyclic `T: X<..>` bound whose
+/// bounded type mentions one of the impl's own type params, so a `T::Assoc` /
+/// `<T as X>::Assoc` in an associated type's value still resolves. A bound on a fully
+/// concrete type is NOT re-added — its projection normalizes straight through that type's own
+/// types impl, and requiring it would re-create the very impl-level cycle ranking breaks
+/// (`Lit`'s types impl needing `Neg`'s, needing `Lit`'s).
+fn types_impl_generics(
+    generics: &Generics,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> Generics {
+    let mut g = remove_cyclic_bounds(generics, replacing_table);
+    let types_bounds = types_where_predicates(generics, replacing_table, &TokenStream::new());
+    if !types_bounds.is_empty() {
+        g.make_where_clause().predicates.extend(types_bounds);
+    }
+    g
+}
+
+/// The `T: XTypes<..>` predicates `types_impl_generics` adds, with each types trait spelled
+/// `#prefix XTypes` — the inductive and Final impls, outside `ranked_traits`, need them too:
+/// a ranked bound does not imply its types trait (see `process_trait_item_for_ranked`).
+fn types_where_predicates(
+    generics: &Generics,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    prefix: &TokenStream,
+) -> Vec<WherePredicate> {
+    let param_idents: std::collections::HashSet<Ident> = generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            GenericParam::Type(t) => Some(t.ident.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut types_bounds: Vec<WherePredicate> = Vec::new();
+    replace_constraints(&mut generics.clone(), |ty, trait_path| {
+        // Only a bound `TraitReplacer` ranks (`Trait`/`self::Trait`): a qualified
+        // `super::Trait` bound deliberately reaches the ORIGINAL trait, whose projections
+        // stay on it — and its type need not have a types impl here at all.
+        let offset = self_offset(&trait_path);
+        if trait_path.segments.len() == offset + 1 {
+            let seg = &trait_path.segments[offset];
+            if let Some((trait_, _, _)) = replacing_table.get(&seg.ident) {
+                if !assoc_type_idents(trait_).is_empty() && type_mentions_idents(&ty, &param_idents)
+                {
+                    let types_ident = ranked_types_trait_name(&seg.ident);
+                    let args = &seg.arguments;
+                    types_bounds.push(parse_quote!(#ty: #prefix #types_ident #args));
+                }
+            }
+        }
+        Some((ty, trait_path))
+    });
+    types_bounds
+}
+
+/// `T: XTypes<.., Assoc = X>` for every cyclic `T: X<.., Assoc = X>` bound that
+/// `types_where_predicates` leaves out — a constraint on a type that doesn't mention the impl's
+/// own type params (`Odd: Evaluate<Output = u64>`, `Self: ..`). `TraitReplacer` strips the
+/// constraint off the ranked bound, so the inductive and Final impls re-state it here. Never
+/// added to a types impl: a concrete sibling's types impl must not depend back on this one.
+fn constrained_types_predicates(
+    generics: &Generics,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    prefix: &TokenStream,
+) -> Vec<WherePredicate> {
+    let param_idents: std::collections::HashSet<Ident> = generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            GenericParam::Type(t) => Some(t.ident.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut out: Vec<WherePredicate> = Vec::new();
+    replace_constraints(&mut generics.clone(), |ty, trait_path| {
+        let offset = self_offset(&trait_path);
+        if trait_path.segments.len() == offset + 1 {
+            let seg = &trait_path.segments[offset];
+            if let Some((trait_, _, _)) = replacing_table.get(&seg.ident) {
+                if !assoc_type_idents(trait_).is_empty()
+                    && has_assoc_constraint_args(&seg.arguments)
+                    && !type_mentions_idents(&ty, &param_idents)
+                {
+                    let types_ident = ranked_types_trait_name(&seg.ident);
+                    let args = &seg.arguments;
+                    out.push(parse_quote!(#ty: #prefix #types_ident #args));
+                }
+            }
+        }
+        Some((ty, trait_path))
+    });
+    out
+}
+
+fn has_assoc_constraint_args(args: &PathArguments) -> bool {
+    matches!(args, PathArguments::AngleBracketed(angle_args) if angle_args.args.iter().any(|arg| {
+        matches!(arg, GenericArgument::AssocType(_) | GenericArgument::Constraint(_))
+    }))
+}
+
+/// Whether any path in `ty` starts with one of `idents` (`T`, `Vec<T>`, `T::Assoc`, ...).
+fn type_mentions_idents(ty: &Type, idents: &std::collections::HashSet<Ident>) -> bool {
+    struct Find<'a>(&'a std::collections::HashSet<Ident>, bool);
+    impl<'ast> syn::visit::Visit<'ast> for Find<'_> {
+        fn visit_path(&mut self, path: &'ast Path) {
+            if path.leading_colon.is_none()
+                && path.segments.first().is_some_and(|s| self.0.contains(&s.ident))
+            {
+                self.1 = true;
+            }
+            syn::visit::visit_path(self, path);
+        }
+    }
+    let mut finder = Find(idents, false);
+    syn::visit::Visit::visit_type(&mut finder, ty);
+    finder.1
+}
+
 /// Like `remove_cyclic_bounds`, but PRESERVES a cyclic bound whose bounded type is a bare type
 /// parameter of the impl (`impl<T: Cb> …` / `where T: Cb`). The FINAL delegating impl retains
 /// the real, un-ranked `T: Cb` (which the inductive/leaf frames strip and rank away) so C4 can
@@ -240,6 +684,7 @@ fn subst_bare_self_in_generics(generics: &Generics, self_ty: &Type) -> Generics
 fn emit_impl_items_leaf(
     impl_: &ItemImpl,
     trait_: &ItemTrait,
+    traits: &[ItemTrait],
     support_infinite_cycle: bool,
     decycle: &Path,
 ) -> TokenStream {
@@ -260,8 +705,12 @@ fn emit_impl_items_leaf(
     for item in impl_.items.iter() {
         match item {
             ImplItem::Fn(ImplItemFn {
-                defaultness, sig, ..
+                attrs,
+                defaultness,
+                sig,
+                ..
             }) => {
+                let cfgs = method_attrs(attrs, AttrSite::Floor);
                 let mut sig = sig.clone();
                 // Don't replace Self — the leaf impl is for Wrapper<SelfTy>,
                 // so Self should resolve to Wrapper<SelfTy> to match the ranked trait.
@@ -272,15 +721,102 @@ fn emit_impl_items_leaf(
                     input.reduce_pat(param_ix);
                 }
 
-                if support_infinite_cycle {
+                let erase = impl_.attrs.iter().any(is_erase_marker);
+                if erase && !is_erasable(&sig) {
+                    abort!(
+                        &sig.ident,
+                        "decycle: `{}` can't be erased: `#[decycle(erase)]` re-enters a method \
+                         through a value of another type",
+                        sig.ident;
+                        hint = "only `&self` and `self` methods without generics of their own, \
+                                and with no other `Self` in the signature, can be erased"
+                    );
+                }
+                if support_infinite_cycle && erase {
+                    // `#[decycle(erase)]`: one instantiation, `Self::Erased`, re-enters for
+                    // all of them, at full height through its Final impl.
+                    let by_ref = sig.receiver().is_some_and(|r| r.reference.is_some());
+                    let args: Vec<TokenStream> =
+                        sig.inputs.iter().skip(1).map(|p| p.variable()).collect();
+                    let trait_args = &impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments;
+                    let call = quote! {
+                        <<Self as #decycle::Erase>::Erased as super::super::#trait_ident #trait_args>::#{&sig.ident}(
+                            #(if by_ref) { & } __dcl_erased #(for arg in &args) {, #arg}
+                        )
+                    };
+                    output.extend(quote! {
+                        #(for attr in &cfgs) { #attr }
+                        #defaultness #sig {
+                            let __dcl_erased = <Self as #decycle::Erase>::erase(
+                                #(if by_ref) { self } #(else) { &self }
+                            );
+                            #(if sig.unsafety.is_some()) { unsafe { #call } }
+                            #(else) { #call }
+                        }
+                    });
+                } else if support_infinite_cycle {
                     // Call-argument position: `variable()` emits only the bare ident for a
                     // `Pat::Ident` (dropping `mut`/`by_ref`/subpatterns, which aren't valid
                     // expression syntax), matching the delegate path's `FnArgScheme::variable`
                     // usage. A raw `quote!(#pat)` here would reproduce `mut`-qualified idents
                     // as `f(mut n)` — invalid expression syntax.
-                    let fn_call_args: Vec<TokenStream> =
-                        sig.inputs.iter().map(|p| p.variable()).collect();
-                    let margs = type_const_idents(&sig.generics);
+                    let key_generics = without_method_rank(&sig.generics);
+                    let tf = trait_
+                        .items
+                        .iter()
+                        .find_map(|it| match it {
+                            TraitItem::Fn(tf) if tf.sig.ident == sig.ident => Some(tf),
+                            _ => None,
+                        })
+                        .unwrap_or_else(|| {
+                            abort!(
+                                &sig.ident,
+                                "method `{}` not found on #[decycle] trait `{}`",
+                                &sig.ident,
+                                trait_ident
+                            )
+                        });
+                    // `#[decycle(erase(S => dyn Stream))]`: re-enter at `S = &mut dyn Stream`,
+                    // whatever `S` this floor was reached with. The trait's params map to the
+                    // impl's by position.
+                    let erased_params = erased_method_params(tf);
+                    let erased: Vec<(&Ident, &ErasedParam)> = key_generics
+                        .type_params()
+                        .map(|tp| &tp.ident)
+                        .zip(&erased_params)
+                        .collect();
+                    let mut erasures = TokenStream::new();
+                    let fn_call_args: Vec<TokenStream> = sig
+                        .inputs
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, input)| {
+                            let param = match input {
+                                FnArg::Typed(PatType { ty, .. }) => match &**ty {
+                                    Type::Reference(r) => erased.iter().find(|(ident, _)| {
+                                        matches!(&*r.elem, Type::Path(p) if p.qself.is_none() && p.path.is_ident(*ident))
+                                    }),
+                                    _ => None,
+                                },
+                                FnArg::Receiver(_) => None,
+                            };
+                            match param {
+                                Some((_, param)) => {
+                                    let local = name!("__dcl_erased_{}", ix);
+                                    let mutability = &param.mutability;
+                                    erasures.extend(quote! {
+                                        let #mutability #local: &#mutability (#{&param.object}) = #{input.variable()};
+                                    });
+                                    quote!(&#mutability #local)
+                                }
+                                None => input.variable(),
+                            }
+                        })
+                        .collect();
+                    let margs: Vec<TokenStream> = match reentry_margs(tf) {
+                        Some(margs) if !erased.is_empty() => margs.iter().map(|a| quote!(#a)).collect(),
+                        _ => type_const_idents(&key_generics).iter().map(|i| quote!(#i)).collect(),
+                    };
                     let mk = name!("__Mk_{}_{}", trait_ident, &sig.ident);
                     let fa = name!("__Fp_{}_{}", trait_ident, &sig.ident);
 
@@ -288,21 +824,6 @@ fn emit_impl_items_leaf(
                     // recompute the same mask here (positional, so impl-side renames of
                     // trait/method generics don't matter). Marker keys stay unfiltered.
                     let (s_used, tmask, mmask) = {
-                        let tf = trait_
-                            .items
-                            .iter()
-                            .find_map(|it| match it {
-                                TraitItem::Fn(tf) if tf.sig.ident == sig.ident => Some(tf),
-                                _ => None,
-                            })
-                            .unwrap_or_else(|| {
-                                abort!(
-                                    &sig.ident,
-                                    "method `{}` not found on #[decycle] trait `{}`",
-                                    &sig.ident,
-                                    trait_ident
-                                )
-                            });
                         let s_ident = name!("DclSelf");
                         let s_ty: Type = parse_quote!(#s_ident);
                         let trait_args = trait_.generics.ty_generics();
@@ -336,13 +857,15 @@ fn emit_impl_items_leaf(
                         is_syntactically_unsized(&impl_.self_ty),
                         &trait_.generics,
                         &self_targs,
-                        Some(&sig.generics),
+                        erased.is_empty().then_some(&key_generics),
                     );
 
                     // The transmute names BOTH types: `usize` source (the registry hands the
                     // fn pointer out by value) and the declared alias target — never `_`.
                     output.extend(quote! {
+                        #(for attr in &cfgs) { #attr }
                         #defaultness #sig {
+                            #erasures
                             let __dcl_f = unsafe {
                                 ::core::mem::transmute::<
                                     ::core::primitive::usize,
@@ -366,12 +889,39 @@ fn emit_impl_items_leaf(
                     });
                 } else {
                     output.extend(quote! {
+                        #(for attr in &cfgs) { #attr }
                         #defaultness #sig {
                             ::core::unimplemented!("decycle: cycle limit reached")
                         }
                     });
                 }
             }
+            // Defined once, on the trait's types impl, instead of per rank.
+            ImplItem::Type(_) if !assoc_type_idents(trait_).is_empty() => {}
+            // Consts have no run-time re-entry: one that reads another member's const is cut
+            // off here in either mode, failing to compile only if it's evaluated this deep.
+            ImplItem::Const(ImplItemConst {
+                attrs,
+                ident,
+                ty,
+                expr,
+                ..
+            }) if const_is_cyclic(
+                    expr,
+                    traits,
+                    &impl_.generics.type_params().map(|t| t.ident.to_string()).collect::<Vec<_>>(),
+                ) =>
+            {
+                let msg = format!(
+                    "decycle: cycle limit reached evaluating `{}::{}` (raise `recurse_level` if \
+                     the recursion terminates)",
+                    trait_ident, ident
+                );
+                output.extend(quote! {
+                    #(for attr in cfg_attrs(attrs)) { #attr }
+                    const #ident: #ty = ::core::panic!(#msg);
+                })
+            }
             o => output.extend(quote!(#o)),
         }
     }
@@ -381,6 +931,9 @@ fn emit_impl_items_leaf(
 fn emit_impl_items_delegate(
     impl_: &ItemImpl,
     path: TokenStream,
+    // The path associated types are delegated through: the trait's types trait when it has
+    // one (the ranked trait only inherits them), else `path` itself.
+    types_path: TokenStream,
     // C4: `Some((trait_, decycle))` for a bare-param impl's Final delegating impl ⇒ prepend
     // `build_bareparam_registrations`'s prologue to each delegated method body. `None` for
     // every other Final impl ⇒ empty prologue, byte-identical to before C4.
@@ -390,7 +943,10 @@ fn emit_impl_items_delegate(
     for item in &impl_.items {
         match item {
             ImplItem::Fn(ImplItemFn {
-                sig, defaultness, ..
+                attrs,
+                sig,
+                defaultness,
+                ..
             }) => {
                 let mut sig = sig.clone();
                 for (ix, input) in sig.inputs.iter_mut().enumerate() {
@@ -409,6 +965,8 @@ fn emit_impl_items_delegate(
                     None => TokenStream::new(),
                 };
                 output.extend(quote! {
+                    #(for attr in method_attrs(attrs, AttrSite::Final)) { #attr }
+                    #[allow(deprecated)]
                     #defaultness #sig {
                         #prologue
                         #path::#{&sig.ident}
@@ -422,17 +980,25 @@ fn emit_impl_items_delegate(
                 })
             }
             ImplItem::Type(ImplItemType {
-                ident, generics, ..
+                attrs,
+                ident,
+                generics,
+                ..
             }) => {
                 // A GAT's own params (`type Assoc<'a, T>;`) must be threaded onto the RHS
                 // too — `= #path::#ident;` silently dropped them, defaulting every param
                 // and very likely resolving to the wrong (or no) instantiation.
                 let targs = generics.ty_generics();
                 output.extend(quote! {
-                    type #ident #generics = #path::#ident #targs;
+                    #(for attr in cfg_attrs(attrs)) { #attr }
+                    type #ident #generics = #types_path::#ident #targs;
                 })
             }
-            ImplItem::Const(ImplItemConst { ident, ty, .. }) => output.extend(quote! {
+            ImplItem::Const(ImplItemConst {
+                attrs, ident, ty, ..
+            }) => output.extend(quote! {
+                #(for attr in cfg_attrs(attrs)) { #attr }
+                #[allow(deprecated)]
                 const #ident: #ty = #path::#ident;
             }),
             other => abort!(
@@ -506,78 +1072,778 @@ fn replace_self_and_desugar_impl_trait(sig: &mut Signature, base_self_ty: &Type)
     }
 }
 
-fn process_trait_item_for_ranked(item: &TraitItem) -> TraitItem {
+/// `types`: the trait's associated type idents and its types trait (`XTypes<'a, T>`, see
+/// `ranked_types_trait_name`) when it declares any. Each method then reaches them as
+/// `<Self as XTypes<'a, T>>::Assoc` under a method-level `where Self: XTypes<'a, T>` — NOT
+/// through a supertrait: the inductive impl's `Self: XRanked<Rank>` bound would elaborate it
+/// into a where-bound on `Self`, which shadows the types impl and leaves a generic impl's
+/// `Self::Assoc` unnormalizable inside its own body. An impl method that leaves the bound
+/// off (all of them) proves it through the types impl instead.
+///
+/// A default method with a cyclic where-bound, or whose body calls into the cycle
+/// (`body_cycle_call`), is declared without its default and that bound: every impl `finalize`
+/// emits carries a ranked copy instead (`inherit_cyclic_defaults`). So is a default const naming
+/// a #[decycle] trait.
+///
+/// Any other cyclic where-bound not naming the method's generics moves onto the impls
+/// (`hoist_method_cyclic_bounds`, which rejects one naming an impl's generics), and the
+/// method's own cyclic bounds are ranked
+/// (`rank_method_generics`).
+fn process_trait_item_for_ranked(
+    item: &TraitItem,
+    trait_: &ItemTrait,
+    traits: &[ItemTrait],
+    types: Option<(&[Ident], &Path)>,
+    prefix: &TokenStream,
+) -> TraitItem {
     let mut item = item.clone();
-    if let TraitItem::Fn(TraitItemFn { sig, .. }) = &mut item {
+    if let TraitItem::Fn(f) = &mut item {
+        f.attrs.retain(|attr| !attr.path().is_ident("decycle"));
+        name_must_use(&mut f.attrs, &trait_.ident, &f.sig.ident);
+    }
+    if let TraitItem::Const(TraitItemConst { default, .. }) = &mut item {
+        if default
+            .as_ref()
+            .is_some_and(|(_, expr)| copies_default_const(expr, traits))
+        {
+            *default = None;
+        }
+    }
+    if let TraitItem::Fn(TraitItemFn {
+        sig,
+        default,
+        semi_token,
+        ..
+    }) = &mut item
+    {
+        let split = match default {
+            Some(block) => split_copied_default(trait_, sig, block, traits),
+            None => Some(split_method_cyclic_bounds(sig, traits)),
+        };
+        if let Some((_, kept)) = split {
+            *default = None;
+            *semi_token = Some(Default::default());
+            sig.generics.where_clause = None;
+            if !kept.is_empty() {
+                sig.generics.make_where_clause().predicates.extend(kept);
+            }
+        }
+        rank_method_generics(sig, traits, prefix);
         // Keep the default body (if any) verbatim: a leaf/inductive impl of the ranked
         // trait that doesn't override a defaulted method must fall back to it, exactly
         // like an impl of the original trait would — stripping it here made every
         // defaulted method abstract on the ranked trait, so an impl omitting it hit
         // E0046 even though the original trait supplies a default.
         replace_self_and_desugar_impl_trait(sig, &parse_quote!(Self));
+        if let Some((assoc, types_path)) = types {
+            let mut qualify = QualifySelfAssoc { assoc, types_path };
+            syn::visit_mut::VisitMut::visit_signature_mut(&mut qualify, sig);
+            if let Some(default) = default {
+                syn::visit_mut::VisitMut::visit_block_mut(&mut qualify, default);
+            }
+            sig.generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(Self: #types_path));
+        }
     }
     item
 }
 
-/// Replaces `Self` with a concrete type in a type tree, including `Self::Assoc`
-/// projections (which become `<S as Trait<...>>::Assoc` — a bare `S::Assoc` would not
-/// resolve on a generic `S`).
-struct SelfSubst<'a> {
-    s_ty: &'a Type,
-    trait_path: &'a Path,
+/// A `TraitReplacer` for a method's own bounds at `rank_type`, spelling each ranked and types
+/// trait `#prefix XRanked` / `#prefix XTypes`.
+fn method_bounds_replacer(traits: &[ItemTrait], rank_type: Type, prefix: &TokenStream) -> TraitReplacer {
+    TraitReplacer {
+        table: traits
+            .iter()
+            .map(|t| {
+                let path: Path = parse_quote!(#prefix #{ranked_trait_name(&t.ident)});
+                (t.ident.clone(), (ranked_trait_rank_loc(t), path))
+            })
+            .collect(),
+        rank_type,
+        types_table: traits
+            .iter()
+            .filter_map(|t| {
+                let assoc = assoc_type_idents(t);
+                let path: Path = parse_quote!(#prefix #{ranked_types_trait_name(&t.ident)});
+                (!assoc.is_empty()).then(|| (t.ident.clone(), (assoc, path)))
+            })
+            .collect(),
+    }
 }
 
-impl syn::visit_mut::VisitMut for SelfSubst<'_> {
-    fn visit_type_mut(&mut self, ty: &mut Type) {
-        if let Type::Path(TypePath { qself: None, path }) = ty {
-            if path.is_ident("Self") {
-                *ty = self.s_ty.clone();
-                return;
-            }
-            if path.leading_colon.is_none()
-                && path.segments.len() > 1
-                && path.segments[0].ident == "Self"
-            {
-                let mut segments: Punctuated<PathSegment, Token![::]> =
-                    self.trait_path.segments.clone();
-                for seg in path.segments.iter().skip(1) {
-                    segments.push(seg.clone());
-                }
-                *ty = Type::Path(TypePath {
-                    qself: Some(QSelf {
-                        lt_token: Default::default(),
-                        ty: Box::new(self.s_ty.clone()),
-                        position: self.trait_path.segments.len(),
-                        as_token: Some(Default::default()),
-                        gt_token: Default::default(),
-                    }),
-                    path: Path {
-                        leading_colon: self.trait_path.leading_colon,
-                        segments,
-                    },
-                });
-                return;
-            }
-        }
-        syn::visit_mut::visit_type_mut(self, ty);
+/// The hidden rank param `rank_method_generics` gives a method.
+fn method_rank_param() -> Ident {
+    name!("MRank")
+}
+
+/// For a method with a cyclic bound on its own generics (`fn visit<V: Ca>(..) where Wrap<V>:
+/// Cb`, see `has_method_generic_cyclic_bound`), appends a `MRank` type param and ranks those
+/// bounds at it (`V: #prefix CaRanked<MRank>`). Only the caller can prove them, at whatever rank
+/// its own bounds are — so `MRank` is inferred at each call, not tied to the impl's rank: a
+/// `child.visit(v)` one rank down still passes the same `v`. An explicit turbofish gets a
+/// trailing `_` for it (`RouteRankedCalls`).
+fn rank_method_generics(sig: &mut Signature, traits: &[ItemTrait], prefix: &TokenStream) {
+    if !has_method_generic_cyclic_bound(sig, traits) {
+        return;
     }
+    let rank = method_rank_param();
+    sig.generics.params.push(parse_quote!(#rank));
+    syn::visit_mut::VisitMut::visit_generics_mut(
+        &mut method_bounds_replacer(traits, parse_quote!(#rank), prefix),
+        &mut sig.generics,
+    );
 }
 
-/// Gives every elided lifetime (`&T`, `'_`) a fresh name. Fn-pointer types and free fns
-/// have no `self` elision rule, so the re-entry fn / floor alias signatures must be fully
-/// named before output elision can be resolved against the receiver.
-struct LtNamer {
-    fresh: Vec<Lifetime>,
-    counter: usize,
+/// `sig`'s generics without the hidden `MRank` param: the floor's re-entry key, which the
+/// registrations spell from the original trait's signature.
+fn without_method_rank(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    let rank = method_rank_param();
+    generics.params = std::mem::take(&mut generics.params)
+        .into_iter()
+        .filter(|p| !matches!(p, GenericParam::Type(t) if t.ident == rank))
+        .collect();
+    generics
 }
 
-impl LtNamer {
-    fn fresh_lt(&mut self) -> Lifetime {
-        let lt = Lifetime::new(&format!("'__dcl{}", self.counter), Span::call_site());
-        self.counter += 1;
-        self.fresh.push(lt.clone());
-        lt
-    }
+/// Routes the calls in a ranked body: appends `_` to an explicit turbofish on a call of a method
+/// `rank_method_generics` gave a hidden `MRank` (`self.visit::<V>(..)`, `Cv::visit::<V>(..)`), or
+/// of a helper `emit_ranked_helpers` copied (`walk::<T>(..)`), and renames a call of an inherent
+/// helper to its ranked copy (`self.total()` → `self.total_ranked()`, see `ranked_helper_name`).
+///
+/// A call is only routed where it syntactically resolves there — a `Trait::m` / `<T as Trait>::m`
+/// path, or a receiver or path prefix whose type is bounded by (or, for a concrete type,
+/// implements) the trait declaring `m`; a bare `walk(..)` path for a free helper; a `Ty::m` path
+/// or a receiver of type `Ty` for an inherent helper on `Ty`. Anything else — `"1".parse::<usize>()`
+/// next to a cyclic `fn parse<V: Cb>`, `vec.len()` next to a helper `fn len` — is left alone.
+#[derive(Clone, Default)]
+struct RouteRankedCalls {
+    /// Each #[decycle] trait's methods with a hidden `MRank`.
+    inferred: HashMap<Ident, std::collections::HashSet<Ident>>,
+    /// Each #[decycle] trait's supertraits in the batch, and the trait each ranked name
+    /// (`CaRanked`) stands for — bounds are read before or after `TraitReplacer`.
+    supertraits: HashMap<Ident, Vec<Ident>>,
+    ranked_names: HashMap<Ident, Ident>,
+    /// The #[decycle] traits implemented for each concrete self type, by its last segment.
+    implemented: HashMap<Ident, std::collections::HashSet<Ident>>,
+    /// The free helpers.
+    fns: std::collections::HashSet<Ident>,
+    /// The inherent helpers, by their self type's last segment.
+    renamed: HashMap<Ident, std::collections::HashSet<Ident>>,
+    /// The enclosing impl's self type.
+    self_ty: Option<Type>,
+    /// The #[decycle] traits each generic param (and `Self`) in scope is bounded by.
+    bounds: Vec<HashMap<Ident, std::collections::HashSet<Ident>>>,
+    /// The declared type of each binding in scope (innermost last), `None` where untyped.
+    scopes: Vec<HashMap<Ident, Option<Type>>>,
+}
+
+impl RouteRankedCalls {
+    fn new(
+        traits: &[ItemTrait],
+        replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+        helper_impls: &[ItemImpl],
+        fns: &[ItemFn],
+    ) -> Self {
+        let mut implemented: HashMap<Ident, std::collections::HashSet<Ident>> = HashMap::new();
+        for (trait_ident, (_, _, impls)) in replacing_table {
+            for impl_ in impls {
+                if let Some(ty) = type_last_ident(&impl_.self_ty) {
+                    implemented.entry(ty).or_default().insert(trait_ident.clone());
+                }
+            }
+        }
+        // A trait method's name is never taken for an inherent one.
+        let trait_methods: std::collections::HashSet<&Ident> = traits
+            .iter()
+            .flat_map(|t| &t.items)
+            .filter_map(|item| match item {
+                TraitItem::Fn(f) => Some(&f.sig.ident),
+                _ => None,
+            })
+            .collect();
+        let mut renamed: HashMap<Ident, std::collections::HashSet<Ident>> = HashMap::new();
+        for impl_ in helper_impls {
+            if let Some(ty) = type_last_ident(&impl_.self_ty) {
+                let methods = ranked_helper_methods(impl_, traits, replacing_table)
+                    .map(|f| f.sig.ident.clone())
+                    .filter(|ident| !trait_methods.contains(ident));
+                renamed.entry(ty).or_default().extend(methods);
+            }
+        }
+        RouteRankedCalls {
+            inferred: traits
+                .iter()
+                .map(|t| {
+                    let methods = t
+                        .items
+                        .iter()
+                        .filter_map(|item| match item {
+                            TraitItem::Fn(f) if has_method_generic_cyclic_bound(&f.sig, traits) => {
+                                Some(f.sig.ident.clone())
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    (t.ident.clone(), methods)
+                })
+                .collect(),
+            supertraits: traits
+                .iter()
+                .map(|t| {
+                    let supers = t.supertraits.iter().filter_map(|b| match b {
+                        TypeParamBound::Trait(tb) if is_cyclic_bound(b, traits) => {
+                            Some(tb.path.segments.last().unwrap().ident.clone())
+                        }
+                        _ => None,
+                    });
+                    (t.ident.clone(), supers.collect())
+                })
+                .collect(),
+            ranked_names: traits.iter().map(|t| (ranked_trait_name(&t.ident), t.ident.clone())).collect(),
+            implemented,
+            fns: fns.iter().map(|f| f.sig.ident.clone()).collect(),
+            renamed,
+            self_ty: None,
+            bounds: vec![HashMap::new()],
+            scopes: vec![HashMap::new()],
+        }
+    }
+
+    /// The #[decycle] trait `path` names, by its own or its ranked name.
+    fn trait_of_path(&self, path: &Path) -> Option<Ident> {
+        let ident = &path.segments.last()?.ident;
+        if self.inferred.contains_key(ident) {
+            Some(ident.clone())
+        } else {
+            self.ranked_names.get(ident).cloned()
+        }
+    }
+
+    /// Records the #[decycle] bounds `generics` puts on its type params (and on `Self`).
+    fn bind_generics(&mut self, generics: &Generics) {
+        let add = |this: &mut Self, ty: &Type, bounds: &Punctuated<TypeParamBound, Token![+]>| {
+            let Type::Path(TypePath { qself: None, path }) = ty else {
+                return;
+            };
+            let Some(param) = path.get_ident() else {
+                return;
+            };
+            let traits: Vec<Ident> = bounds
+                .iter()
+                .filter_map(|b| match b {
+                    TypeParamBound::Trait(tb) => this.trait_of_path(&tb.path),
+                    _ => None,
+                })
+                .collect();
+            this.bounds.last_mut().unwrap().entry(param.clone()).or_default().extend(traits);
+        };
+        for tp in generics.type_params() {
+            let ident = &tp.ident;
+            add(self, &parse_quote!(#ident), &tp.bounds);
+        }
+        for pred in generics.where_clause.iter().flat_map(|wc| &wc.predicates) {
+            if let WherePredicate::Type(pt) = pred {
+                add(self, &pt.bounded_ty, &pt.bounds);
+            }
+        }
+    }
+
+    /// The #[decycle] traits `ty` is bounded by or implements, supertraits included.
+    fn traits_of(&self, ty: &Type) -> std::collections::HashSet<Ident> {
+        let mut out = std::collections::HashSet::new();
+        let mut pending: Vec<Ident> = match ty {
+            Type::Reference(r) => return self.traits_of(&r.elem),
+            Type::Paren(p) => return self.traits_of(&p.elem),
+            Type::Group(g) => return self.traits_of(&g.elem),
+            Type::Path(TypePath { qself: None, path }) => {
+                let param = path.get_ident();
+                let bounded = param.and_then(|p| self.bounds.iter().rev().find_map(|b| b.get(p)));
+                match bounded {
+                    Some(traits) => {
+                        let mut traits: Vec<Ident> = traits.iter().cloned().collect();
+                        if path.is_ident("Self") {
+                            traits.extend(self.self_ty.as_ref().map(|t| self.traits_of(t)).into_iter().flatten());
+                        }
+                        traits
+                    }
+                    None if path.is_ident("Self") => {
+                        return self.self_ty.as_ref().map(|t| self.traits_of(t)).unwrap_or_default()
+                    }
+                    None => {
+                        let last = &path.segments.last().unwrap().ident;
+                        self.implemented.get(last).into_iter().flatten().cloned().collect()
+                    }
+                }
+            }
+            _ => Vec::new(),
+        };
+        while let Some(t) = pending.pop() {
+            if out.insert(t.clone()) {
+                pending.extend(self.supertraits.get(&t).into_iter().flatten().cloned());
+            }
+        }
+        out
+    }
+
+    /// The last segment of the concrete type `ty` stands for, `Self` resolved.
+    fn self_type_ident(&self, ty: &Type) -> Option<Ident> {
+        match ty {
+            Type::Reference(r) => self.self_type_ident(&r.elem),
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("Self") => {
+                self.self_type_ident(self.self_ty.as_ref()?)
+            }
+            _ => type_last_ident(ty),
+        }
+    }
+
+    /// The type of the receiver `expr`, if it is syntactically a binding's declared one, or one
+    /// of the module's types built in place (`X`, `Wrap(v)`, `Sum { .. }`).
+    fn receiver_ty(&self, expr: &Expr) -> Option<Type> {
+        let known = |path: &Path| {
+            let last = &path.segments.last()?.ident;
+            (self.implemented.contains_key(last) || self.renamed.contains_key(last))
+                .then(|| Type::Path(TypePath { qself: None, path: path.clone() }))
+        };
+        match expr {
+            Expr::Paren(e) => self.receiver_ty(&e.expr),
+            Expr::Group(e) => self.receiver_ty(&e.expr),
+            Expr::Reference(e) => self.receiver_ty(&e.expr),
+            Expr::Unary(ExprUnary { op: UnOp::Deref(_), expr, .. }) => self.receiver_ty(expr),
+            Expr::Path(ExprPath { qself: None, path, .. }) => match path.get_ident() {
+                Some(ident) => match self.scopes.iter().rev().find_map(|scope| scope.get(ident)) {
+                    Some(ty) => ty.clone(),
+                    None => known(path),
+                },
+                None => known(path),
+            },
+            Expr::Call(ExprCall { func, .. }) => match &**func {
+                Expr::Path(ExprPath { qself: None, path, .. }) => known(path),
+                _ => None,
+            },
+            Expr::Struct(ExprStruct { qself: None, path, .. }) => known(path),
+            _ => None,
+        }
+    }
+
+    /// Whether `m`, called on a `ty`, is one with a hidden `MRank`, and whether it is an inherent
+    /// helper there.
+    fn resolve(&self, ty: &Type, m: &Ident) -> (bool, bool) {
+        let inferred = self
+            .traits_of(ty)
+            .iter()
+            .any(|t| self.inferred.get(t).is_some_and(|methods| methods.contains(m)));
+        let renamed = self
+            .self_type_ident(ty)
+            .and_then(|ty| self.renamed.get(&ty))
+            .is_some_and(|methods| methods.contains(m));
+        (inferred, renamed)
+    }
+
+    /// Records the bindings `pat` introduces, of type `ty` if it is one.
+    fn bind(&mut self, pat: &Pat, ty: Option<&Type>) {
+        match pat {
+            Pat::Type(pt) => self.bind(&pt.pat, Some(&pt.ty)),
+            Pat::Ident(pi) if pi.subpat.is_none() => {
+                self.scopes.last_mut().unwrap().insert(pi.ident.clone(), ty.cloned());
+            }
+            _ => {
+                struct Idents(Vec<Ident>);
+                impl<'ast> syn::visit::Visit<'ast> for Idents {
+                    fn visit_pat_ident(&mut self, pi: &'ast PatIdent) {
+                        self.0.push(pi.ident.clone());
+                        syn::visit::visit_pat_ident(self, pi);
+                    }
+                }
+                let mut idents = Idents(Vec::new());
+                syn::visit::Visit::visit_pat(&mut idents, pat);
+                let scope = self.scopes.last_mut().unwrap();
+                scope.extend(idents.0.into_iter().map(|ident| (ident, None)));
+            }
+        }
+    }
+
+    /// Routes the calls in a fn's `block`, with `sig`'s generics and parameters in scope, inside
+    /// an impl of `self_ty` with `outer` generics, if any.
+    fn route_fn(&mut self, outer: Option<(&Type, &Generics)>, sig: &Signature, block: &mut Block) {
+        let saved = self.self_ty.clone();
+        self.bounds.push(HashMap::new());
+        if let Some((self_ty, generics)) = outer {
+            self.self_ty = Some(self_ty.clone());
+            self.bind_generics(generics);
+        }
+        self.route_method(sig, block);
+        self.bounds.pop();
+        self.self_ty = saved;
+    }
+
+    fn route_method(&mut self, sig: &Signature, block: &mut Block) {
+        self.bounds.push(HashMap::new());
+        self.bind_generics(&sig.generics);
+        self.scopes.push(HashMap::new());
+        for input in &sig.inputs {
+            match input {
+                FnArg::Receiver(_) => {
+                    let self_ = Ident::new("self", Span::call_site());
+                    self.scopes.last_mut().unwrap().insert(self_, Some(parse_quote!(Self)));
+                }
+                FnArg::Typed(pt) => self.bind(&pt.pat, Some(&pt.ty)),
+            }
+        }
+        syn::visit_mut::VisitMut::visit_block_mut(self, block);
+        self.scopes.pop();
+        self.bounds.pop();
+    }
+}
+
+/// The last segment of a path type, behind any references.
+fn type_last_ident(ty: &Type) -> Option<Ident> {
+    match ty {
+        Type::Reference(r) => type_last_ident(&r.elem),
+        Type::Paren(p) => type_last_ident(&p.elem),
+        Type::Group(g) => type_last_ident(&g.elem),
+        Type::Path(TypePath { qself: None, path }) => Some(path.segments.last()?.ident.clone()),
+        _ => None,
+    }
+}
+
+impl syn::visit_mut::VisitMut for RouteRankedCalls {
+    fn visit_item_impl_mut(&mut self, impl_: &mut ItemImpl) {
+        let saved = self.self_ty.replace((*impl_.self_ty).clone());
+        self.bounds.push(HashMap::new());
+        self.bind_generics(&impl_.generics);
+        if let Some(trait_) = impl_.trait_.as_ref().and_then(|(_, path, _)| self.trait_of_path(path)) {
+            let self_ = Ident::new("Self", Span::call_site());
+            self.bounds.last_mut().unwrap().entry(self_).or_default().insert(trait_);
+        }
+        syn::visit_mut::visit_item_impl_mut(self, impl_);
+        self.bounds.pop();
+        self.self_ty = saved;
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, f: &mut ImplItemFn) {
+        let sig = f.sig.clone();
+        self.route_method(&sig, &mut f.block);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        self.scopes.push(HashMap::new());
+        syn::visit_mut::visit_block_mut(self, block);
+        self.scopes.pop();
+    }
+
+    fn visit_local_mut(&mut self, local: &mut Local) {
+        // The initializer is in the outer binding's scope.
+        if let Some(init) = &mut local.init {
+            syn::visit_mut::visit_local_init_mut(self, init);
+        }
+        self.bind(&local.pat, None);
+    }
+
+    fn visit_expr_closure_mut(&mut self, closure: &mut ExprClosure) {
+        self.scopes.push(HashMap::new());
+        for input in &closure.inputs {
+            self.bind(input, None);
+        }
+        self.visit_expr_mut(&mut closure.body);
+        self.scopes.pop();
+    }
+
+    fn visit_expr_method_call_mut(&mut self, call: &mut ExprMethodCall) {
+        let (inferred, renamed) = self
+            .receiver_ty(&call.receiver)
+            .map_or((false, false), |ty| self.resolve(&ty, &call.method));
+        if let Some(turbofish) = &mut call.turbofish {
+            if inferred || renamed {
+                turbofish.args.push(parse_quote!(_));
+            }
+        }
+        if renamed {
+            call.method = ranked_helper_name(&call.method);
+        }
+        syn::visit_mut::visit_expr_method_call_mut(self, call);
+    }
+
+    fn visit_expr_path_mut(&mut self, expr_path: &mut ExprPath) {
+        let path = &expr_path.path;
+        let n = path.segments.len();
+        let m = path.segments[n - 1].ident.clone();
+        let (inferred, renamed) = match &expr_path.qself {
+            // `<T as Trait>::m`, or `<T>::m`.
+            Some(qself) if qself.position > 0 && qself.position == n - 1 => {
+                let trait_ = self.trait_of_path(&Path {
+                    leading_colon: None,
+                    segments: path.segments.iter().take(qself.position).cloned().collect(),
+                });
+                let inferred = trait_.is_some_and(|t| self.inferred[&t].contains(&m));
+                (inferred, false)
+            }
+            Some(qself) if qself.position == 0 && n == 1 => self.resolve(&qself.ty, &m),
+            Some(_) => (false, false),
+            None if n == 1 => (path.leading_colon.is_none() && self.fns.contains(&m), false),
+            None => {
+                let prefix = Path {
+                    leading_colon: path.leading_colon,
+                    segments: path.segments.iter().take(n - 1).cloned().collect(),
+                };
+                match self.trait_of_path(&prefix) {
+                    Some(t) => (self.inferred[&t].contains(&m), false),
+                    None => self.resolve(&Type::Path(TypePath { qself: None, path: prefix }), &m),
+                }
+            }
+        };
+        let last = expr_path.path.segments.last_mut().unwrap();
+        if let PathArguments::AngleBracketed(args) = &mut last.arguments {
+            if inferred || renamed {
+                args.args.push(parse_quote!(_));
+            }
+        }
+        if renamed {
+            last.ident = ranked_helper_name(&last.ident);
+        }
+        syn::visit_mut::visit_expr_path_mut(self, expr_path);
+    }
+}
+
+/// The name of an inherent helper method's ranked copy (`emit_ranked_helpers`).
+fn ranked_helper_name(ident: &Ident) -> Ident {
+    name!("{}_ranked", ident)
+}
+
+/// `#ty: #Trait` for each cyclic bound in `generics` — the ones `remove_cyclic_bounds` strips.
+fn cyclic_predicates(
+    generics: &Generics,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> Vec<WherePredicate> {
+    let mut preds: Vec<WherePredicate> = Vec::new();
+    replace_constraints(&mut generics.clone(), |ty, trait_path| {
+        if trait_path
+            .segments
+            .last()
+            .is_some_and(|seg| replacing_table.contains_key(&seg.ident))
+        {
+            preds.push(parse_quote!(#ty: #trait_path));
+        }
+        Some((ty, trait_path))
+    });
+    preds
+}
+
+/// Whether `sig` (inline, in its where clause, or in an `impl Trait` argument) names a
+/// #[decycle] trait in a bound.
+fn sig_has_cyclic_bound(sig: &Signature, traits: &[ItemTrait]) -> bool {
+    use syn::visit::Visit;
+    struct Finder<'a>(&'a [ItemTrait], bool);
+    impl<'ast> Visit<'ast> for Finder<'_> {
+        fn visit_type_param_bound(&mut self, bound: &'ast TypeParamBound) {
+            self.1 |= is_cyclic_bound(bound, self.0);
+            syn::visit::visit_type_param_bound(self, bound);
+        }
+        fn visit_type_trait_object(&mut self, _: &'ast TypeTraitObject) {}
+    }
+    let mut finder = Finder(traits, false);
+    finder.visit_signature(sig);
+    finder.1
+}
+
+/// The inherent methods `emit_ranked_helpers` copies: every method of an impl with a cyclic
+/// bound of its own, else those with one in their signature.
+fn ranked_helper_methods<'a>(
+    impl_: &'a ItemImpl,
+    traits: &'a [ItemTrait],
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> impl Iterator<Item = &'a ImplItemFn> + 'a {
+    let impl_cyclic = !cyclic_predicates(&impl_.generics, replacing_table).is_empty();
+    impl_.items.iter().filter_map(move |item| match item {
+        ImplItem::Fn(f) if impl_cyclic || sig_has_cyclic_bound(&f.sig, traits) => Some(f),
+        _ => None,
+    })
+}
+
+/// Ranked copies of the helpers `process_module` hands over with cyclic bounds (`FinalizeArgs::
+/// fns`, and the inherent impls in `contents`), emitted in `shadowing_module` for the cycle
+/// bodies there to call (`RouteRankedCalls`). A free fn keeps its name, shadowing the original
+/// the glob import brings in; an inherent method becomes `ranked_helper_name`, in an impl
+/// without the cyclic bounds, which move onto the method. Like a method with a cyclic bound on
+/// its own generics (`rank_method_generics`), each copy takes a hidden caller-inferred `MRank`
+/// and every bound in it, body included, is ranked at that.
+#[allow(clippy::too_many_arguments)]
+fn emit_ranked_helpers(
+    helper_impls: &[ItemImpl],
+    fns: &[ItemFn],
+    traits: &[ItemTrait],
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    trait_replacer_table: &HashMap<Ident, (usize, Path)>,
+    types_table: &HashMap<Ident, (Vec<Ident>, Path)>,
+    router: &RouteRankedCalls,
+    decycle: &Path,
+) -> TokenStream {
+    use syn::visit_mut::VisitMut;
+    let rank = method_rank_param();
+    let prefix = quote!(#{name!("ranked_traits")}::);
+    let rank_copy = |sig: &mut Signature, block: &mut Block, outer: &Generics, self_ty: Option<&Type>| {
+        let mut scope = outer.clone();
+        scope.params.extend(sig.generics.params.iter().cloned());
+        if let Some(wc) = &sig.generics.where_clause {
+            scope.make_where_clause().predicates.extend(wc.predicates.iter().cloned());
+        }
+        let mut types_bounds = types_where_predicates(&scope, replacing_table, &prefix);
+        types_bounds.extend(constrained_types_predicates(&scope, replacing_table, &prefix));
+        sig.generics.params.push(parse_quote!(#rank));
+        sig.generics.make_where_clause().predicates.extend(types_bounds);
+        // Before `TraitReplacer`, which respells `dyn Node` as the object alias.
+        let mut dyn_router = RouteDynCalls::new(traits, &rank, decycle);
+        dyn_router.route_fn(sig, block);
+        sig.generics.make_where_clause().predicates.extend(dyn_router.rank_depth_predicate(decycle));
+        let mut replacer = TraitReplacer {
+            table: trait_replacer_table.clone(),
+            rank_type: parse_quote!(#rank),
+            types_table: types_table.clone(),
+        };
+        replacer.visit_signature_mut(sig);
+        replacer.visit_block_mut(block);
+        let sig = sig.clone();
+        router.clone().route_fn(self_ty.map(|ty| (ty, outer)), &sig, block);
+    };
+    let mut out = TokenStream::new();
+    for item_fn in fns {
+        let mut item_fn = item_fn.clone();
+        item_fn.vis = Visibility::Inherited;
+        rank_copy(&mut item_fn.sig, &mut item_fn.block, &Generics::default(), None);
+        out.extend(quote! {
+            #[allow(dead_code, clippy::needless_lifetimes)]
+            #item_fn
+        });
+    }
+    for impl_ in helper_impls {
+        let cyclic = cyclic_predicates(&impl_.generics, replacing_table);
+        let methods: Vec<ImplItemFn> = ranked_helper_methods(impl_, traits, replacing_table)
+            .map(|f| {
+                let mut f = f.clone();
+                f.vis = Visibility::Inherited;
+                f.sig.ident = ranked_helper_name(&f.sig.ident);
+                if !cyclic.is_empty() {
+                    f.sig.generics.make_where_clause().predicates.extend(cyclic.iter().cloned());
+                }
+                rank_copy(&mut f.sig, &mut f.block, &impl_.generics, Some(&impl_.self_ty));
+                f
+            })
+            .collect();
+        if methods.is_empty() {
+            continue;
+        }
+        let g = remove_cyclic_bounds(&impl_.generics, replacing_table);
+        out.extend(quote! {
+            #(for attr in cfg_attrs(&impl_.attrs)) { #attr }
+            #[allow(dead_code)]
+            impl #{g.impl_generics()} #{&impl_.self_ty} #{&g.where_clause} {
+                #(for f in &methods) { #f }
+            }
+        });
+    }
+    out
+}
+
+/// Rewrites `Self::Assoc` (for an `Assoc` in `assoc`) to `<Self as #types_path>::Assoc` — the
+/// ranked trait no longer declares it, so the unqualified shorthand would not resolve.
+struct QualifySelfAssoc<'a> {
+    assoc: &'a [Ident],
+    types_path: &'a Path,
+}
+
+impl syn::visit_mut::VisitMut for QualifySelfAssoc<'_> {
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        let path = &type_path.path;
+        if type_path.qself.is_none()
+            && path.leading_colon.is_none()
+            && path.segments.len() >= 2
+            && path.segments[0].ident == "Self"
+            && path.segments[0].arguments.is_none()
+            && self.assoc.contains(&path.segments[1].ident)
+        {
+            let mut segments = self.types_path.segments.clone();
+            segments.extend(path.segments.iter().skip(1).cloned());
+            *type_path = TypePath {
+                qself: Some(QSelf {
+                    lt_token: Default::default(),
+                    ty: Box::new(parse_quote!(Self)),
+                    position: self.types_path.segments.len(),
+                    as_token: Some(Default::default()),
+                    gt_token: Default::default(),
+                }),
+                path: Path {
+                    leading_colon: self.types_path.leading_colon,
+                    segments,
+                },
+            };
+        }
+        syn::visit_mut::visit_type_path_mut(self, type_path);
+    }
+}
+
+/// Replaces `Self` with a concrete type in a type tree, including `Self::Assoc`
+/// projections (which become `<S as Trait<...>>::Assoc` — a bare `S::Assoc` would not
+/// resolve on a generic `S`).
+struct SelfSubst<'a> {
+    s_ty: &'a Type,
+    trait_path: &'a Path,
+}
+
+impl syn::visit_mut::VisitMut for SelfSubst<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if path.is_ident("Self") {
+                *ty = self.s_ty.clone();
+                return;
+            }
+            if path.leading_colon.is_none()
+                && path.segments.len() > 1
+                && path.segments[0].ident == "Self"
+            {
+                let mut segments: Punctuated<PathSegment, Token![::]> =
+                    self.trait_path.segments.clone();
+                for seg in path.segments.iter().skip(1) {
+                    segments.push(seg.clone());
+                }
+                *ty = Type::Path(TypePath {
+                    qself: Some(QSelf {
+                        lt_token: Default::default(),
+                        ty: Box::new(self.s_ty.clone()),
+                        position: self.trait_path.segments.len(),
+                        as_token: Some(Default::default()),
+                        gt_token: Default::default(),
+                    }),
+                    path: Path {
+                        leading_colon: self.trait_path.leading_colon,
+                        segments,
+                    },
+                });
+                return;
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// Gives every elided lifetime (`&T`, `'_`) a fresh name. Fn-pointer types and free fns
+/// have no `self` elision rule, so the re-entry fn / floor alias signatures must be fully
+/// named before output elision can be resolved against the receiver. Like the other elision
+/// visitors below, it leaves `fn(&str) -> &str` and `Fn(&str) -> &str` alone: their elided
+/// lifetimes are bound there, not the method's.
+struct LtNamer {
+    fresh: Vec<Lifetime>,
+    counter: usize,
+}
+
+impl LtNamer {
+    fn fresh_lt(&mut self) -> Lifetime {
+        let lt = Lifetime::new(&format!("'__dcl{}", self.counter), Span::call_site());
+        self.counter += 1;
+        self.fresh.push(lt.clone());
+        lt
+    }
 }
 
 impl syn::visit_mut::VisitMut for LtNamer {
@@ -595,6 +1861,10 @@ impl syn::visit_mut::VisitMut for LtNamer {
             *l = self.fresh_lt();
         }
     }
+
+    fn visit_type_bare_fn_mut(&mut self, _: &mut TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(&mut self, _: &mut ParenthesizedGenericArguments) {}
 }
 
 fn count_elided_lifetimes(ty: &Type) -> usize {
@@ -613,6 +1883,8 @@ fn count_elided_lifetimes(ty: &Type) -> usize {
                 self.0 += 1;
             }
         }
+        fn visit_type_bare_fn(&mut self, _: &'ast TypeBareFn) {}
+        fn visit_parenthesized_generic_arguments(&mut self, _: &'ast ParenthesizedGenericArguments) {}
     }
     let mut c = Counter(0);
     syn::visit::Visit::visit_type(&mut c, ty);
@@ -635,27 +1907,12 @@ fn subst_elided_lifetimes(ty: &mut Type, subst: &Lifetime) {
                 *l = self.0.clone();
             }
         }
+        fn visit_type_bare_fn_mut(&mut self, _: &mut TypeBareFn) {}
+        fn visit_parenthesized_generic_arguments_mut(&mut self, _: &mut ParenthesizedGenericArguments) {}
     }
     syn::visit_mut::VisitMut::visit_type_mut(&mut Subst(subst.clone()), ty);
 }
 
-fn distinct_lifetimes_in(tys: impl Iterator<Item = Type>) -> Vec<Lifetime> {
-    struct Collector(Vec<Lifetime>);
-    impl<'ast> syn::visit::Visit<'ast> for Collector {
-        fn visit_lifetime(&mut self, l: &'ast Lifetime) {
-            if l.ident != "_" && l.ident != "static" && !self.0.iter().any(|s| s.ident == l.ident)
-            {
-                self.0.push(l.clone());
-            }
-        }
-    }
-    let mut c = Collector(Vec::new());
-    for ty in tys {
-        syn::visit::Visit::visit_type(&mut c, &ty);
-    }
-    c.0
-}
-
 /// The type/const generic args of a path's last segment (lifetimes and associated-type
 /// constraints dropped) — the marker/alias instantiation list for a trait path.
 fn nonlifetime_path_args(args: &PathArguments) -> Vec<GenericArgument> {
@@ -940,8 +2197,9 @@ pub fn is_syntactically_unsized(ty: &Type) -> bool {
 }
 
 /// True iff every occurrence of `s_ident` among `tys` is the IMMEDIATE referent of a `&`/`&mut`
-/// (never by value, never nested inside some other generic position like `Vec<S>` or `(S, S)`
-/// — those need `S: Sized` regardless of being behind a reference themselves). Marking the
+/// or the sole argument of a `Box`/`Rc`/`Arc` (a `self: Box<Self>` receiver; never by value,
+/// never nested inside some other generic position like `Vec<S>` or `(S, S)` — those need
+/// `S: Sized` regardless of being behind a reference themselves). Marking the
 /// `#fa` alias's / `#re` fn's own `S` param `?Sized` is only sound when this holds (F-M1): an
 /// unsized `Self` — `impl Ca for str` — is fine for `fn ca(&self, ...)` (receiver `&S`) but a
 /// hypothetical by-value-`Self`-returning or -taking method would need `S: Sized` regardless,
@@ -954,12 +2212,26 @@ fn s_ident_only_behind_ref<'a>(tys: impl Iterator<Item = &'a Type>, s_ident: &Id
     fn is_bare_ident(ty: &Type, ident: &Ident) -> bool {
         matches!(ty, Type::Path(TypePath { qself: None, path }) if path.is_ident(ident))
     }
+    fn is_owning_pointer_to(ty: &Type, ident: &Ident) -> bool {
+        let Type::Path(TypePath { qself: None, path }) = ty else {
+            return false;
+        };
+        let last = path.segments.last().unwrap();
+        let PathArguments::AngleBracketed(ab) = &last.arguments else {
+            return false;
+        };
+        ["Box", "Rc", "Arc"].iter().any(|p| last.ident == p)
+            && ab.args.len() == 1
+            && matches!(ab.args.first(), Some(GenericArgument::Type(t)) if is_bare_ident(t, ident))
+    }
     impl<'a, 'ast> syn::visit::Visit<'ast> for Check<'a> {
         fn visit_type(&mut self, ty: &'ast Type) {
             if let Type::Reference(r) = ty {
                 if is_bare_ident(&r.elem, self.s_ident) {
                     return; // a bare `&S`/`&mut S` — the safe shape, nothing more to check
                 }
+            } else if is_owning_pointer_to(ty, self.s_ident) {
+                return;
             } else if is_bare_ident(ty, self.s_ident) {
                 self.unsafe_found = true;
                 return;
@@ -1128,8 +2400,9 @@ fn impl_has_bare_param_cyclic_bound(
 // `B<T>: Cb`, whose only impl needs `T: Default` — so a registration is only safe to emit when
 // every non-cyclic bound reachable through the impl's own cyclic-bound graph is already among
 // the registering impl's own bounds. The check below is conservative and purely syntactic
-// (structural type unification + string-equality predicate comparison — no semantic subtrait/
-// blanket-impl reasoning) and fails closed on anything it can't establish: the caller then
+// (structural type unification + string-equality predicate comparison — no semantic subtrait
+// reasoning; a blanket impl only yields to a more specific match) and fails closed on anything
+// it can't establish, short of a ground predicate, which holds globally: the caller then
 // skips the registration, leaving a clean isolated lookup panic instead of an uncompilable
 // macro expansion.
 // ---------------------------------------------------------------------------------------------
@@ -1343,13 +2616,616 @@ fn normalize_obligation_targets(impl_: &mut ItemImpl, rules: &[(Type, Type)]) {
     syn::visit_mut::VisitMut::visit_generics_mut(&mut NormalizeTargets { rules }, &mut impl_.generics);
 }
 
-/// Strips a leading `::` (global-path) marker so `::core::fmt::Debug` and `core::fmt::Debug`
-/// render identically (F1b: same-spelling paths must compare equal). Deliberately shallow —
-/// only the top-level `leading_colon` token is cleared, not any nested path (e.g. inside a
-/// generic argument); a genuinely different path (different segments, or a different crate
-/// root reached via `self::`/`crate::`) must keep comparing unequal.
-fn strip_leading_colon(path: &mut Path) {
-    path.leading_colon = None;
+/// Rewrites `Fn(...)`-sugar on a #[decycle] trait (`B: Cb(usize) -> usize`) to the
+/// angle-bracketed form it stands for (`B: Cb<(usize,), Output = usize>`), so every later
+/// stage — bound stripping, `TraitReplacer`, the types-trait predicates — only ever sees
+/// ordinary generic arguments and an `Output` constraint.
+struct DesugarFnSugar<'a> {
+    traits: &'a [ItemTrait],
+}
+
+impl syn::visit_mut::VisitMut for DesugarFnSugar<'_> {
+    fn visit_path_segment_mut(&mut self, seg: &mut PathSegment) {
+        if let PathArguments::Parenthesized(pa) = &seg.arguments {
+            if self.traits.iter().any(|t| t.ident == seg.ident) {
+                seg.arguments = PathArguments::AngleBracketed(fn_sugar_to_angle_bracketed(pa));
+            }
+        }
+        syn::visit_mut::visit_path_segment_mut(self, seg);
+    }
+}
+
+/// The ident a generic parameter declares (a lifetime's without its `'`).
+fn generic_param_ident(p: &GenericParam) -> &Ident {
+    match p {
+        GenericParam::Type(t) => &t.ident,
+        GenericParam::Const(c) => &c.ident,
+        GenericParam::Lifetime(l) => &l.lifetime.ident,
+    }
+}
+
+/// Whether `b` names a #[decycle] trait the way `TraitReplacer` ranks it (`Trait`, `self::Trait`).
+fn is_cyclic_bound(b: &TypeParamBound, traits: &[ItemTrait]) -> bool {
+    let TypeParamBound::Trait(tb) = b else {
+        return false;
+    };
+    let mut path = tb.path.clone();
+    strip_leading_self(&mut path);
+    path.segments.len() == 1 && traits.iter().any(|t| t.ident == path.segments[0].ident)
+}
+
+/// Whether `pred` mentions one of `idents` anywhere (bounded type or bound arguments).
+fn predicate_mentions(pred: &WherePredicate, idents: &[String]) -> bool {
+    quote!(#pred)
+        .to_string()
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|word| idents.iter().any(|i| i == word))
+}
+
+/// Whether a const's value may read another member's const (`<Term as Node>::DEPTH`, `T::DEPTH`):
+/// it names a #[decycle] trait or one of `params`.
+fn const_is_cyclic(expr: &Expr, traits: &[ItemTrait], params: &[String]) -> bool {
+    quote!(#expr)
+        .to_string()
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|word| params.iter().any(|p| p == word) || traits.iter().any(|t| t.ident == word))
+}
+
+/// Whether `sig` has a cyclic bound on one of its own type params (`fn visit<V: Ca>(..)`,
+/// `where Wrap<V>: Cb`). Such a method is ranked like any other, but only the caller can prove
+/// the bound — so a Final impl enters it through the user's own body instead of the ranked
+/// chain, and registers its re-entry per instantiation there (`build_method_bound_registrations`).
+fn has_method_generic_cyclic_bound(sig: &Signature, traits: &[ItemTrait]) -> bool {
+    let params: Vec<String> = sig.generics.type_params().map(|t| t.ident.to_string()).collect();
+    sig.generics
+        .type_params()
+        .any(|tp| tp.bounds.iter().any(|b| is_cyclic_bound(b, traits)))
+        || sig.generics.where_clause.iter().flat_map(|wc| &wc.predicates).any(|pred| {
+            matches!(pred, WherePredicate::Type(pt) if pt.bounds.iter().any(|b| is_cyclic_bound(b, traits)))
+                && predicate_mentions(pred, &params)
+        })
+}
+
+/// Splits `sig`'s where-clause into the cyclic predicates that don't name its own generics —
+/// hoisted onto the impl, where they are ranked like any impl bound — and the ones it keeps.
+fn split_method_cyclic_bounds(
+    sig: &Signature,
+    traits: &[ItemTrait],
+) -> (Vec<WherePredicate>, Vec<WherePredicate>) {
+    let method_params: Vec<String> = sig
+        .generics
+        .params
+        .iter()
+        .map(|p| generic_param_ident(p).to_string())
+        .collect();
+    sig.generics
+        .where_clause
+        .iter()
+        .flat_map(|wc| wc.predicates.iter().cloned())
+        .partition(|pred| {
+            matches!(pred, WherePredicate::Type(pt) if pt.bounds.iter().any(|b| is_cyclic_bound(b, traits)))
+                && !predicate_mentions(pred, &method_params)
+        })
+}
+
+/// Splits the where-clause of a default method `finalize` copies into impls (see
+/// [`inherit_cyclic_defaults`]) like `split_method_cyclic_bounds`, or `None` if the default stays
+/// inherited: neither it nor `trait_` has a cyclic bound (`trait_cyclic_predicates`,
+/// `cyclic_assoc_type_bounds`), and its body doesn't reach the cycle (`body_cycle_call`).
+fn split_copied_default(
+    trait_: &ItemTrait,
+    sig: &Signature,
+    default: &Block,
+    traits: &[ItemTrait],
+) -> Option<(Vec<WherePredicate>, Vec<WherePredicate>)> {
+    let (hoisted, kept) = split_method_cyclic_bounds(sig, traits);
+    (!hoisted.is_empty()
+        || has_method_generic_cyclic_bound(sig, traits)
+        || !trait_cyclic_predicates(trait_, traits).is_empty()
+        || !cyclic_assoc_type_bounds(trait_, traits).is_empty()
+        || body_cycle_call(default, traits).is_some())
+    .then_some((hoisted, kept))
+}
+
+/// Where a default body may call into the cycle without a bound of its own saying so: a path
+/// through a #[decycle] trait (`Cb::cb(&b, n)`, `<B as Cb>::cb`), or a #[decycle] trait's method
+/// called on anything but `self` (`B.cb(n)`, `child.ca(n)`), macro arguments included. Left on
+/// the ranked trait, such a call could only resolve through the original trait; copied into an
+/// impl, it is ranked against the impl's own bounds.
+fn body_cycle_call(block: &Block, traits: &[ItemTrait]) -> Option<Span> {
+    use proc_macro2::TokenTree;
+    fn flatten(tokens: TokenStream, out: &mut Vec<TokenTree>) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Group(g) => flatten(g.stream(), out),
+                tt => out.push(tt),
+            }
+        }
+    }
+    let methods: std::collections::HashSet<String> = traits
+        .iter()
+        .flat_map(|t| &t.items)
+        .filter_map(|item| match item {
+            TraitItem::Fn(f) => Some(f.sig.ident.to_string()),
+            _ => None,
+        })
+        .collect();
+    let mut tokens = Vec::new();
+    flatten(quote!(#block), &mut tokens);
+    let punct = |i: usize, c: char| matches!(tokens.get(i), Some(TokenTree::Punct(p)) if p.as_char() == c);
+    let ident_is = |i: usize, words: &[&str]| {
+        matches!(tokens.get(i), Some(TokenTree::Ident(s)) if words.iter().any(|w| s == w))
+    };
+    tokens.iter().enumerate().find_map(|(i, tt)| {
+        let TokenTree::Ident(ident) = tt else {
+            return None;
+        };
+        let reaches = if traits.iter().any(|t| t.ident == *ident) {
+            (punct(i + 1, ':') && punct(i + 2, ':')) || (i >= 1 && ident_is(i - 1, &["as"]))
+        } else if !methods.contains(&ident.to_string()) {
+            false
+        } else if i >= 1 && punct(i - 1, '.') {
+            !(i >= 2 && ident_is(i - 2, &["self", "Self"]))
+        } else if i >= 2 && punct(i - 1, ':') && punct(i - 2, ':') {
+            !(i >= 3 && ident_is(i - 3, &["self", "Self"]))
+        } else {
+            false
+        };
+        reaches.then(|| ident.span())
+    })
+}
+
+/// Whether `inherit_cyclic_defaults` copies a default const with value `expr` into the impls, to
+/// be ranked like theirs: it names a #[decycle] trait (`<Self as Base>::DEPTH + 1`).
+fn copies_default_const(expr: &Expr, traits: &[ItemTrait]) -> bool {
+    const_is_cyclic(expr, traits, &[])
+}
+
+/// Maps `trait_`'s type, const and lifetime params to `impl_`'s arguments for them (or, where the
+/// impl leaves one out, its default), for [`TraitArgSubst`]. A const argument is kept as a braced
+/// `Type::Verbatim` (`{ 7 }`), which reads the same as a generic argument and as an expression.
+fn trait_arg_subst(trait_: &ItemTrait, impl_: &ItemImpl) -> (HashMap<Ident, Type>, HashMap<Ident, Lifetime>) {
+    let trait_args = &impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments;
+    let mut args = nonlifetime_path_args(trait_args).into_iter();
+    let mut subst: HashMap<Ident, Type> = HashMap::new();
+    for param in &trait_.generics.params {
+        match param {
+            GenericParam::Type(tp) => {
+                let ty = match args.next() {
+                    Some(GenericArgument::Type(ty)) => Some(ty),
+                    Some(_) => None,
+                    None => tp.default.clone(),
+                };
+                if let Some(ty) = ty {
+                    subst.insert(tp.ident.clone(), ty);
+                }
+            }
+            GenericParam::Const(cp) => {
+                let value = match args.next() {
+                    Some(GenericArgument::Type(ty)) => Some(quote!(#ty)),
+                    Some(GenericArgument::Const(expr)) => Some(quote!(#expr)),
+                    Some(_) => None,
+                    None => cp.default.as_ref().map(|expr| quote!(#expr)),
+                };
+                if let Some(value) = value {
+                    subst.insert(cp.ident.clone(), Type::Verbatim(quote!({ #value })));
+                }
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    let lt_subst: HashMap<Ident, Lifetime> = match trait_args {
+        PathArguments::AngleBracketed(ab) => trait_
+            .generics
+            .lifetimes()
+            .map(|l| l.lifetime.ident.clone())
+            .zip(ab.args.iter().filter_map(|arg| match arg {
+                GenericArgument::Lifetime(lt) => Some(lt.clone()),
+                _ => None,
+            }))
+            .collect(),
+        _ => HashMap::new(),
+    };
+    (subst, lt_subst)
+}
+
+/// Rewrites trait-side items into an impl's terms ([`trait_arg_subst`]).
+struct TraitArgSubst<'a>(&'a HashMap<Ident, Type>, &'a HashMap<Ident, Lifetime>);
+
+impl syn::visit_mut::VisitMut for TraitArgSubst<'_> {
+    // The impl's arguments are in its own terms: never substituted again.
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if let Some(rep) = path.get_ident().and_then(|ident| self.0.get(ident)) {
+                *ty = rep.clone();
+                return;
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+    fn visit_lifetime_mut(&mut self, lt: &mut Lifetime) {
+        if let Some(rep) = self.1.get(&lt.ident) {
+            *lt = rep.clone();
+        }
+    }
+    // A const param read as a value (`N * 2`, `[u8; N]`).
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(ExprPath { qself: None, path, .. }) = expr {
+            if let Some(Type::Verbatim(value)) = path.get_ident().and_then(|ident| self.0.get(ident)) {
+                *expr = Expr::Verbatim(value.clone());
+                return;
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// Copies into `impl_` every default method of its #[decycle] trait that it doesn't override
+/// and whose bounds name a #[decycle] trait (`fn eval_twice(&self) where Other: Evaluate`) or
+/// whose body calls into the cycle (`split_copied_default`), moving those cyclic bounds that
+/// don't name the method's own generics onto the impl's own where-clause. The copy is then
+/// ranked like an impl-provided method: its body runs one rank below the impl, with the hoisted
+/// bound discharged by the impl chain. The ranked trait declares such a method without its
+/// default or cyclic bounds (`process_trait_item_for_ranked`): ranked in place, the bound would
+/// have to be proven at every generic-rank call site. A default const naming a #[decycle] trait
+/// is copied the same way (`copies_default_const`).
+fn inherit_cyclic_defaults(impl_: &mut ItemImpl, traits: &[ItemTrait]) {
+    let Some(trait_) = impl_
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .and_then(|seg| traits.iter().find(|t| t.ident == seg.ident))
+    else {
+        return;
+    };
+    let (subst, lt_subst) = trait_arg_subst(trait_, impl_);
+    let trait_path = &impl_.trait_.as_ref().unwrap().1.clone();
+    let generics = &impl_.generics;
+    let impl_bounded = !trait_cyclic_predicates(trait_, traits).is_empty()
+        || !cyclic_assoc_type_bounds(trait_, traits).is_empty()
+        || generics
+            .type_params()
+            .flat_map(|tp| &tp.bounds)
+            .chain(generics.where_clause.iter().flat_map(|wc| &wc.predicates).flat_map(|pred| match pred {
+                WherePredicate::Type(pt) => pt.bounds.iter().collect(),
+                _ => Vec::new(),
+            }))
+            .any(|b| is_cyclic_bound(b, traits))
+        || impl_.items.iter().any(|item| {
+            matches!(item, ImplItem::Fn(f) if !split_method_cyclic_bounds(&f.sig, traits).0.is_empty())
+        });
+    let mut hoisted: Vec<WherePredicate> = Vec::new();
+    // A default copied only for a call in its body.
+    let mut unbounded_call: Option<(Span, &Ident)> = None;
+    for item in &trait_.items {
+        if let TraitItem::Const(TraitItemConst {
+            attrs,
+            ident,
+            generics,
+            ty,
+            default: Some((_, expr)),
+            ..
+        }) = item
+        {
+            if copies_default_const(expr, traits)
+                && !impl_
+                    .items
+                    .iter()
+                    .any(|it| matches!(it, ImplItem::Const(c) if c.ident == *ident))
+            {
+                let mut item: ImplItem = parse_quote! {
+                    #(for attr in attrs) { #attr }
+                    const #ident #generics: #ty = #expr;
+                };
+                syn::visit_mut::VisitMut::visit_impl_item_mut(&mut TraitArgSubst(&subst, &lt_subst), &mut item);
+                impl_.items.push(item);
+            }
+            continue;
+        }
+        let TraitItem::Fn(TraitItemFn {
+            attrs,
+            sig,
+            default: Some(block),
+            ..
+        }) = item
+        else {
+            continue;
+        };
+        if impl_
+            .items
+            .iter()
+            .any(|it| matches!(it, ImplItem::Fn(f) if f.sig.ident == sig.ident))
+        {
+            continue;
+        }
+        let Some((cyclic, kept)) = split_copied_default(trait_, sig, block, traits) else {
+            continue;
+        };
+        if cyclic.is_empty() && !has_method_generic_cyclic_bound(sig, traits) {
+            unbounded_call = unbounded_call.or_else(|| Some((body_cycle_call(block, traits)?, &sig.ident)));
+        }
+        if let Some(clash) = sig.generics.params.iter().find(|p| {
+            impl_
+                .generics
+                .params
+                .iter()
+                .any(|ip| generic_param_ident(ip) == generic_param_ident(p))
+        }) {
+            abort!(
+                clash,
+                "the default `{}` is copied into each impl of `{}`, and this generic parameter \
+                 clashes with one of an impl's",
+                sig.ident,
+                trait_.ident;
+                help = syn::spanned::Spanned::span(&impl_.generics) => "rename it here, or in the trait"
+            )
+        }
+        let mut sig = sig.clone();
+        sig.generics.where_clause = None;
+        if !kept.is_empty() {
+            sig.generics.make_where_clause().predicates.extend(kept);
+        }
+        let mut method: ImplItemFn = parse_quote! {
+            #(for attr in attrs) { #attr }
+            #sig #block
+        };
+        syn::visit_mut::VisitMut::visit_impl_item_fn_mut(&mut TraitArgSubst(&subst, &lt_subst), &mut method);
+        for mut pred in cyclic {
+            syn::visit_mut::VisitMut::visit_where_predicate_mut(&mut TraitArgSubst(&subst, &lt_subst), &mut pred);
+            reject_generic_method_bound(&impl_.generics, trait_path, &sig.ident, &pred);
+            if !hoisted.iter().any(|h| quote!(#h).to_string() == quote!(#pred).to_string()) {
+                hoisted.push(pred);
+            }
+        }
+        impl_.items.push(ImplItem::Fn(method));
+    }
+    // With no #[decycle] bound on the impl to rank that call against, it would fail as an
+    // unresolvable `XRanked<_>` (E0283).
+    if let (Some((call, method)), true, false) = (unbounded_call, hoisted.is_empty(), impl_bounded) {
+        abort!(
+            call,
+            "decycle: the default `{}` calls into the cycle here, but this impl of `{}` has no \
+             #[decycle] bound to rank the call against",
+            method, trait_.ident;
+            help = syn::spanned::Spanned::span(&impl_.self_ty) =>
+                "state the edge on the impl (`where B: Cb`), or on `{}` itself", method
+        )
+    }
+    if !hoisted.is_empty() {
+        impl_.generics.make_where_clause().predicates.extend(hoisted);
+    }
+}
+
+/// Aborts on a cyclic where-predicate of `method` to be moved onto `impl_` that names the impl's
+/// generics (`where W<T>: Cb` in `impl<T> Ca for W<T>`, or `Self` there): on the impl, it would
+/// narrow it to the instantiations meeting it, where the method only asked its callers for it.
+/// Any other predicate holds for every instantiation or none.
+fn reject_generic_method_bound(
+    generics: &Generics,
+    trait_path: &Path,
+    method: &Ident,
+    pred: &WherePredicate,
+) {
+    let mut params: Vec<String> = generics
+        .params
+        .iter()
+        .filter(|p| !matches!(p, GenericParam::Lifetime(_)))
+        .map(|p| generic_param_ident(p).to_string())
+        .collect();
+    if params.is_empty() {
+        return;
+    }
+    params.push("Self".to_owned());
+    if predicate_mentions(pred, &params) {
+        abort!(
+            pred,
+            "decycle: `{}` is moved onto this impl of `{}` to be ranked, but depends on its \
+             generic parameters, so it would no longer be implemented where `{}` doesn't hold",
+            quote!(#pred).to_string().replace(" :", ":"),
+            quote!(#trait_path).to_string().replace(' ', ""),
+            quote!(#pred).to_string().replace(" :", ":");
+            note = "a #[decycle] bound on `{}` that doesn't name its own generic parameters is \
+                    an edge of the whole impl", method;
+            hint = "state it on the impl, if every use of the impl meets it"
+        )
+    }
+}
+
+/// Moves the cyclic where-predicates of each of `impl_`'s methods that don't name the method's own
+/// generics (`fn eval(&self) where Other: Evaluate`) onto the impl (`split_method_cyclic_bounds`):
+/// ranked on the method, they would have to be proven by every caller. One naming the impl's
+/// generics is rejected (`reject_generic_method_bound`).
+fn hoist_method_cyclic_bounds(impl_: &mut ItemImpl, traits: &[ItemTrait]) {
+    let Some((_, trait_path, _)) = &impl_.trait_ else {
+        return;
+    };
+    let mut hoisted: Vec<WherePredicate> = Vec::new();
+    for item in &mut impl_.items {
+        let ImplItem::Fn(f) = item else { continue };
+        let (preds, kept) = split_method_cyclic_bounds(&f.sig, traits);
+        if preds.is_empty() {
+            continue;
+        }
+        for pred in &preds {
+            reject_generic_method_bound(&impl_.generics, trait_path, &f.sig.ident, pred);
+        }
+        f.sig.generics.where_clause = None;
+        if !kept.is_empty() {
+            f.sig.generics.make_where_clause().predicates.extend(kept);
+        }
+        for pred in preds {
+            if !hoisted.iter().any(|h| quote!(#h).to_string() == quote!(#pred).to_string()) {
+                hoisted.push(pred);
+            }
+        }
+    }
+    if !hoisted.is_empty() {
+        impl_.generics.make_where_clause().predicates.extend(hoisted);
+    }
+}
+
+/// `trait_`'s own cyclic constraints, as where-predicates: the #[decycle] bounds of its params
+/// (`trait Ca<T: Cb>` ⇒ `T: Cb`) and of its where-clause (`where Child<Self>: Cb`), each
+/// stripped of its other bounds. The ranked trait is declared without them
+/// (`strip_trait_cyclic_bounds`) and every impl carries them instead (`hoist_trait_cyclic_bounds`).
+fn trait_cyclic_predicates(trait_: &ItemTrait, traits: &[ItemTrait]) -> Vec<WherePredicate> {
+    let cyclic = |bounds: &Punctuated<TypeParamBound, Token![+]>| -> Punctuated<TypeParamBound, Token![+]> {
+        bounds.iter().filter(|b| is_cyclic_bound(b, traits)).cloned().collect()
+    };
+    let from_params = trait_.generics.type_params().filter_map(|tp| {
+        let bounds = cyclic(&tp.bounds);
+        let ident = &tp.ident;
+        (!bounds.is_empty()).then(|| parse_quote!(#ident: #bounds))
+    });
+    let from_where = trait_
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|wc| &wc.predicates)
+        .filter_map(|pred| {
+            let WherePredicate::Type(pt) = pred else {
+                return None;
+            };
+            let mut pt = pt.clone();
+            pt.bounds = cyclic(&pt.bounds);
+            (!pt.bounds.is_empty()).then_some(WherePredicate::Type(pt))
+        });
+    from_params.chain(from_where).collect()
+}
+
+/// `generics` of a #[decycle] trait with the bounds `trait_cyclic_predicates` collects removed.
+/// Kept on the ranked trait they would name the original trait, which no ranked impl can
+/// prove; ranked at the trait's own rank, an inductive impl could only prove them by the
+/// induction it is itself a step of.
+fn strip_trait_cyclic_bounds(generics: &Generics, traits: &[ItemTrait]) -> Generics {
+    let mut generics = generics.clone();
+    for tp in generics.type_params_mut() {
+        tp.bounds = tp.bounds.iter().filter(|b| !is_cyclic_bound(b, traits)).cloned().collect();
+        if tp.bounds.is_empty() {
+            tp.colon_token = None;
+        }
+    }
+    if let Some(wc) = &mut generics.where_clause {
+        wc.predicates = wc
+            .predicates
+            .iter()
+            .filter_map(|pred| {
+                let WherePredicate::Type(pt) = pred else {
+                    return Some(pred.clone());
+                };
+                let mut pt = pt.clone();
+                pt.bounds = pt.bounds.iter().filter(|b| !is_cyclic_bound(b, traits)).cloned().collect();
+                (!pt.bounds.is_empty()).then_some(WherePredicate::Type(pt))
+            })
+            .collect();
+    }
+    generics
+}
+
+/// Adds `trait_cyclic_predicates` of `impl_`'s #[decycle] trait to `impl_`'s own where-clause, in
+/// the impl's terms (`impl Ca for A` of `trait Ca where Child<Self>: Cb` ⇒ `where Child<A>: Cb`),
+/// and the edges its associated types make (`assoc_type_edges`), unless it already spells them
+/// out: from there they are ranked like any impl bound, one rank below the impl.
+fn hoist_trait_cyclic_bounds(impl_: &mut ItemImpl, traits: &[ItemTrait]) {
+    let Some(trait_) = impl_
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .and_then(|seg| traits.iter().find(|t| t.ident == seg.ident))
+    else {
+        return;
+    };
+    let (mut subst, lt_subst) = trait_arg_subst(trait_, impl_);
+    subst.insert(Ident::new("Self", Span::call_site()), (*impl_.self_ty).clone());
+    let existing: Vec<String> = impl_
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|wc| &wc.predicates)
+        .map(|pred| quote!(#pred).to_string())
+        .collect();
+    let from_trait = trait_cyclic_predicates(trait_, traits).into_iter().map(|mut pred| {
+        syn::visit_mut::VisitMut::visit_where_predicate_mut(&mut TraitArgSubst(&subst, &lt_subst), &mut pred);
+        pred
+    });
+    let mut hoisted: Vec<WherePredicate> = Vec::new();
+    for pred in from_trait.chain(assoc_type_edges(trait_, impl_, traits)) {
+        let rendered = quote!(#pred).to_string();
+        if !existing.contains(&rendered) && !hoisted.iter().any(|h| quote!(#h).to_string() == rendered) {
+            hoisted.push(pred);
+        }
+    }
+    if !hoisted.is_empty() {
+        impl_.generics.make_where_clause().predicates.extend(hoisted);
+    }
+}
+
+/// An associated type bounded by a #[decycle] trait (`type Child: Cb;`) is an edge to whatever
+/// `impl_` sets it to: `type Child = B;` ⇒ `B: Cb`, in the impl's terms.
+fn assoc_type_edges(trait_: &ItemTrait, impl_: &ItemImpl, traits: &[ItemTrait]) -> Vec<WherePredicate> {
+    let (subst, lt_subst) = trait_arg_subst(trait_, impl_);
+    let self_subst = HashMap::from([(Ident::new("Self", Span::call_site()), (*impl_.self_ty).clone())]);
+    cyclic_assoc_type_bounds(trait_, traits)
+        .into_iter()
+        .filter_map(|(ident, bounds)| {
+            let mut ty = impl_.items.iter().find_map(|item| match item {
+                ImplItem::Type(t) if t.ident == ident && t.generics.params.is_empty() => Some(t.ty.clone()),
+                _ => None,
+            })?;
+            syn::visit_mut::VisitMut::visit_type_mut(&mut TraitArgSubst(&self_subst, &HashMap::new()), &mut ty);
+            let mut pred: WherePredicate = parse_quote!(#ty: #bounds);
+            syn::visit_mut::VisitMut::visit_where_predicate_mut(&mut TraitArgSubst(&subst, &lt_subst), &mut pred);
+            Some(pred)
+        })
+        .collect()
+}
+
+/// The #[decycle] bounds of `trait_`'s non-generic associated types (`type Child: Cb + Clone;` ⇒
+/// `(Child, Cb)`), each stripped of its other bounds. The types trait drops them;
+/// `hoist_trait_cyclic_bounds` makes each an edge of every impl. A generic associated type's
+/// bound holds for each of its arguments, which no where-bound can say: the types trait keeps
+/// it, naming the original trait, so a call through it needs that trait's full path and
+/// restarts the rank.
+fn cyclic_assoc_type_bounds(
+    trait_: &ItemTrait,
+    traits: &[ItemTrait],
+) -> Vec<(Ident, Punctuated<TypeParamBound, Token![+]>)> {
+    trait_
+        .items
+        .iter()
+        .filter_map(|item| {
+            let TraitItem::Type(t) = item else {
+                return None;
+            };
+            let bounds: Punctuated<TypeParamBound, Token![+]> =
+                t.bounds.iter().filter(|b| is_cyclic_bound(b, traits)).cloned().collect();
+            (t.generics.params.is_empty() && !bounds.is_empty()).then(|| (t.ident.clone(), bounds))
+        })
+        .collect()
+}
+
+/// An associated type of the types trait, without the bounds `cyclic_assoc_type_bounds` collects:
+/// every impl carries them as ranked edges instead (`assoc_type_edges`).
+fn without_cyclic_assoc_bounds(item: &TraitItem, traits: &[ItemTrait]) -> TraitItem {
+    let mut item = item.clone();
+    if let TraitItem::Type(t) = &mut item {
+        if t.generics.params.is_empty() {
+            t.bounds = t.bounds.iter().filter(|b| !is_cyclic_bound(b, traits)).cloned().collect();
+            if t.bounds.is_empty() {
+                t.colon_token = None;
+            }
+        }
+    }
+    item
+}
+
+/// Strips a leading `::` (global-path) marker so `::core::fmt::Debug` and `core::fmt::Debug`
+/// render identically (F1b: same-spelling paths must compare equal). Deliberately shallow —
+/// only the top-level `leading_colon` token is cleared, not any nested path (e.g. inside a
+/// generic argument); a genuinely different path (different segments, or a different crate
+/// root reached via `self::`/`crate::`) must keep comparing unequal.
+fn strip_leading_colon(path: &mut Path) {
+    path.leading_colon = None;
 }
 
 /// Renders one `Bounded : Bound` side predicate, substituting `bt` (already substituted by the
@@ -1419,6 +3295,11 @@ fn side_predicate_strings(
     out
 }
 
+/// How many `(trait, type)` nodes `reachable_side_bounds_ok` walks before giving up. A regular
+/// cycle reaches a handful; a non-regular one (`impl<T> Ca for Nest<T> where Nest<Vec<T>>: Ca`)
+/// reaches a new type at every step, so its walk never ends on its own.
+const MAX_REACHABLE_NODES: usize = 256;
+
 /// Is every non-cyclic bound reachable through `target_ty: target_trait`'s cyclic-bound graph
 /// already among `registering_impl`'s own bounds? Walks the graph breadth-first: at each
 /// `(trait, ty)` node, finds every impl of `trait` whose `self_ty` structurally matches `ty`
@@ -1427,7 +3308,8 @@ fn side_predicate_strings(
 /// (substituted the same way). Fails closed — returns `false` — the moment anything can't be
 /// established syntactically: an unknown trait, no matching impl, more than one matching impl
 /// (ambiguous — treated as needing the union, but an actual mismatch between them still fails
-/// via `merge_subst`/string comparison), or an impl type param left unresolved by unification.
+/// via `merge_subst`/string comparison), an impl type param left unresolved by unification, or
+/// a graph past `MAX_REACHABLE_NODES` (polymorphic recursion).
 fn reachable_side_bounds_ok(
     registering_impl: &ItemImpl,
     target_ty: &Type,
@@ -1445,14 +3327,18 @@ fn reachable_side_bounds_ok(
     let mut needed: Vec<String> = Vec::new();
 
     while let Some((trait_ident, ty)) = queue.pop_front() {
+        let ty = peel_forwarded(&ty, &trait_ident, replacing_table);
         let key = (trait_ident.clone(), quote!(#ty).to_string());
         if !visited.insert(key) {
             continue;
         }
+        if visited.len() > MAX_REACHABLE_NODES {
+            return false;
+        }
         let Some((_, _, impls)) = replacing_table.get(&trait_ident) else {
             return false;
         };
-        let mut matched_any = false;
+        let mut matches = Vec::new();
         for cand in impls {
             let pattern_vars: std::collections::HashSet<Ident> = cand
                 .generics
@@ -1469,7 +3355,15 @@ fn reachable_side_bounds_ok(
             if pattern_vars.iter().any(|v| !subst.contains_key(v)) {
                 return false;
             }
-            matched_any = true;
+            matches.push((cand, subst));
+        }
+        // A blanket impl (`impl<T: Leaf> Ca for T`) unifies with every type; when a more
+        // specific impl matches too, coherence says the blanket one doesn't apply here.
+        if matches.iter().any(|(cand, _)| blanket_self_param(cand).is_none()) {
+            matches.retain(|(cand, _)| blanket_self_param(cand).is_none());
+        }
+        let matched_any = !matches.is_empty();
+        for (cand, subst) in matches {
             needed.extend(side_predicate_strings(
                 &cand.generics,
                 replacing_table,
@@ -1483,7 +3377,53 @@ fn reachable_side_bounds_ok(
             return false;
         }
     }
-    needed.iter().all(|p| own_side.contains(p))
+    // A ground predicate (`A: Leaf`, from a blanket impl unified with `A`) doesn't depend on
+    // the registering frame: it holds globally, or the cycle's own bounds are unsatisfiable.
+    let frame_idents: Vec<String> = registering_impl
+        .generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            GenericParam::Type(t) => Some(t.ident.to_string()),
+            _ => None,
+        })
+        .chain(core::iter::once("Self".to_owned()))
+        .collect();
+    let is_ground = |p: &str| {
+        !p.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .any(|word| frame_idents.iter().any(|ident| ident == word))
+    };
+    needed.iter().all(|p| own_side.contains(p) || is_ground(p))
+}
+
+/// The first reference in a receiver type `ty` whose referent mentions `S` (= `s_ty`): `&self`,
+/// `self: Pin<&mut Self>`, `self: &Box<Self>`. Its lifetime, once named (`LtNamer`), is the one
+/// rustc gives the method's elided output lifetimes.
+fn self_ref_lifetime(ty: &Type, s_ty: &Type) -> Option<Option<Lifetime>> {
+    struct Find<'a> {
+        s_ty: String,
+        found: Option<Option<&'a Lifetime>>,
+    }
+    impl<'a> syn::visit::Visit<'a> for Find<'a> {
+        fn visit_type_reference(&mut self, r: &'a TypeReference) {
+            let elem = &r.elem;
+            let mentions = quote!(#elem)
+                .to_string()
+                .contains(&self.s_ty);
+            if self.found.is_none() && mentions {
+                self.found = Some(r.lifetime.as_ref());
+            }
+            syn::visit::visit_type_reference(self, r);
+        }
+        fn visit_type_bare_fn(&mut self, _: &'a TypeBareFn) {}
+        fn visit_parenthesized_generic_arguments(&mut self, _: &'a ParenthesizedGenericArguments) {}
+    }
+    let mut find = Find {
+        s_ty: quote!(#s_ty).to_string(),
+        found: None,
+    };
+    syn::visit::Visit::visit_type(&mut find, ty);
+    find.found.map(Option::<&Lifetime>::cloned)
 }
 
 /// The elision-normalized re-entry signature shape shared by the alias/re-entry emission
@@ -1514,14 +3454,12 @@ fn normalize_reentry_sig(tf: &TraitItemFn, s_ty: &Type, trait_path_full: &Path)
 
     let mut params: Vec<(TokenStream, Type)> = Vec::new();
     let mut arg_idents: Vec<TokenStream> = Vec::new();
-    let mut recv_ref_slot = None;
+    let mut recv_slot = None;
     for input in &sig.inputs {
         match input {
             FnArg::Receiver(r) => {
                 let ty = (*r.ty).clone();
-                if r.reference.is_some() {
-                    recv_ref_slot = Some(params.len());
-                }
+                recv_slot = Some(params.len());
                 params.push((quote!(__dcl_recv), ty));
                 arg_idents.push(quote!(__dcl_recv));
             }
@@ -1535,51 +3473,43 @@ fn normalize_reentry_sig(tf: &TraitItemFn, s_ty: &Type, trait_path_full: &Path)
             }
         }
     }
-    let mut namer = LtNamer {
-        fresh: Vec::new(),
-        counter: 0,
-    };
-    for (_, ty) in params.iter_mut() {
+    let subst_self = |ty: &mut Type| {
         syn::visit_mut::VisitMut::visit_type_mut(
             &mut SelfSubst {
                 s_ty,
                 trait_path: trait_path_full,
             },
             ty,
-        );
-        syn::visit_mut::VisitMut::visit_type_mut(&mut namer, ty);
+        )
+    };
+    for (_, ty) in params.iter_mut() {
+        subst_self(ty);
     }
     let mut output_ty: Option<Type> = match &sig.output {
         ReturnType::Type(_, t) => Some((**t).clone()),
         ReturnType::Default => None,
     };
     if let Some(t) = output_ty.as_mut() {
-        syn::visit_mut::VisitMut::visit_type_mut(
-            &mut SelfSubst {
-                s_ty,
-                trait_path: trait_path_full,
-            },
-            t,
-        );
+        subst_self(t);
+    }
+    // Fn-pointer types and free fns elide like the method, except for the `self` rule: only
+    // when an elided output lifetime would come from a receiver borrowing `Self` — `&self`, or
+    // nested (`self: Pin<&mut Self>`) — are the inputs named and that lifetime spelled out.
+    // Otherwise everything stays elided, for rustc to resolve exactly as it did the method's
+    // (a single input lifetime, `'static` or hidden in a path included).
+    let mut namer = LtNamer {
+        fresh: Vec::new(),
+        counter: 0,
+    };
+    let recv_borrows_self = recv_slot.is_some_and(|ix| self_ref_lifetime(&params[ix].1, s_ty).is_some());
+    if let (Some(t), true) = (output_ty.as_mut(), recv_borrows_self) {
         if count_elided_lifetimes(t) > 0 {
-            let subst = if let Some(ix) = recv_ref_slot {
-                match &params[ix].1 {
-                    Type::Reference(tr) => tr.lifetime.clone().unwrap(),
-                    _ => unreachable!("reference receiver flattens to a reference type"),
-                }
-            } else {
-                let named = distinct_lifetimes_in(params.iter().map(|(_, t)| t.clone()));
-                if named.len() == 1 {
-                    named.into_iter().next().unwrap()
-                } else {
-                    abort!(
-                        orig_sig,
-                        "cannot resolve the elided output lifetime for #[decycle] unbounded \
-                         re-entry; name the lifetime explicitly"
-                    )
-                }
-            };
-            subst_elided_lifetimes(t, &subst);
+            for (_, ty) in params.iter_mut() {
+                syn::visit_mut::VisitMut::visit_type_mut(&mut namer, ty);
+            }
+            let ix = recv_slot.unwrap();
+            let lt = self_ref_lifetime(&params[ix].1, s_ty).flatten().unwrap();
+            subst_elided_lifetimes(t, &lt);
         }
     }
     NormSig {
@@ -1667,11 +3597,17 @@ fn emit_reentry_items(trait_: &ItemTrait, rank_loc: usize, _decycle: &Path) -> T
     // `impl XxxRanked<rank_loc = ()> for SelfTy`, which trivially proves `S:
     // XxxRanked<..., ()>` for `S = Self` — so the alias instead projects through the RANKED
     // trait at rank `()` (a sibling item in this same `ranked_traits` module, hence no
-    // `super::super::` prefix), which is exactly what that surrounding impl already provides
-    // (including its own `type Assoc = ...;`, copied verbatim from the user's impl).
+    // `super::super::` prefix), which is exactly what that surrounding impl already provides.
+    // A trait with associated types declares them on its rank-free types trait instead (the
+    // ranked trait has none — see `process_trait_item_for_ranked`), so such a trait's alias
+    // projects through that, which the leaf proves via the user's types impl.
     let ranked_trait_ident = name!("{}Ranked", trait_ident);
     let ranked_args_at_leaf = trait_.generics.ty_generics().insert(rank_loc, parse_quote!(()));
-    let ranked_trait_path_leaf: Path = parse_quote!(#ranked_trait_ident #ranked_args_at_leaf);
+    let ranked_trait_path_leaf: Path = if assoc_type_idents(trait_).is_empty() {
+        parse_quote!(#ranked_trait_ident #ranked_args_at_leaf)
+    } else {
+        parse_quote!(#{ranked_types_trait_name(trait_ident)} #trait_args)
+    };
 
     let mut tg = trait_.generics.clone();
     syn::visit_mut::VisitMut::visit_generics_mut(
@@ -1868,8 +3804,13 @@ fn emit_reentry_items(trait_: &ItemTrait, rank_loc: usize, _decycle: &Path) -> T
             .collect();
         let orig_margs = type_const_idents(&orig_sig.generics);
         let do_turbofish = !orig_margs.is_empty() && !sig_has_impl_trait_input(orig_sig);
+        let cfgs: Vec<&Attribute> = cfg_attrs(&trait_.attrs)
+            .into_iter()
+            .chain(cfg_attrs(&tf.attrs))
+            .collect();
 
         out.extend(quote! {
+            #(for attr in &cfgs) { #attr }
             #[allow(dead_code, non_camel_case_types)]
             #[doc(hidden)]
             pub struct #mk<#s_ident: ?::core::marker::Sized
@@ -1884,6 +3825,7 @@ fn emit_reentry_items(trait_: &ItemTrait, rank_loc: usize, _decycle: &Path) -> T
             // to resolve — via the RANKED trait at the leaf (`ranked_trait_path_leaf`), which
             // the surrounding leaf impl trivially provides for `S = Self`, not the real trait
             // (whose only impl needs the whole rank chain — see the comment above).
+            #(for attr in &cfgs) { #attr }
             #[allow(dead_code, non_camel_case_types)]
             #[doc(hidden)]
             pub type #fa<
@@ -1903,7 +3845,8 @@ fn emit_reentry_items(trait_: &ItemTrait, rank_loc: usize, _decycle: &Path) -> T
 
             // `#unsafety #abi` must mirror the trait method: the registered fn's type has to
             // equal the `#fa` alias the floor transmutes to, or the call crosses ABIs.
-            #[allow(dead_code, non_snake_case, unused, clippy::too_many_arguments)]
+            #(for attr in &cfgs) { #attr }
+            #[allow(dead_code, non_snake_case, unused, deprecated, clippy::too_many_arguments)]
             #[doc(hidden)]
             pub #unsafety #abi fn #re<
                 #(for p in &trait_lts) { #{generic_param_bounded(p)}, }
@@ -2025,30 +3968,37 @@ fn build_rule1_registrations(
     );
     let self_unsized = is_syntactically_unsized(&impl_.self_ty);
     let mut out = TokenStream::new();
-    let current_margs = type_const_idents(&current_sig.generics);
-    let fp = fingerprint_expr(
-        decycle,
-        &quote!(Self),
-        self_unsized,
-        &trait_.generics,
-        &self_targs,
-        Some(&current_sig.generics),
-    );
-    out.extend(emit_registration(
-        decycle,
-        &rt,
-        &trait_.ident,
-        &current_sig.ident,
-        &quote!(Self),
-        &self_targs,
-        &current_margs,
-        fp,
-    ));
+    // An erased method registers its erased instantiation with the siblings instead.
+    let current_erased = trait_.items.iter().any(|item| {
+        matches!(item, TraitItem::Fn(tf) if tf.sig.ident == current_sig.ident && !erased_method_params(tf).is_empty())
+    });
+    if !current_erased {
+        let current_margs = type_const_idents(&current_sig.generics);
+        let fp = fingerprint_expr(
+            decycle,
+            &quote!(Self),
+            self_unsized,
+            &trait_.generics,
+            &self_targs,
+            Some(&current_sig.generics),
+        );
+        out.extend(emit_registration(
+            decycle,
+            &rt,
+            &trait_.ident,
+            &current_sig.ident,
+            &quote!(Self),
+            &self_targs,
+            &current_margs,
+            fp,
+        ));
+    }
     for item in &trait_.items {
         let TraitItem::Fn(tf) = item else { continue };
-        if tf.sig.ident == current_sig.ident || method_is_generic(&tf.sig) {
+        if tf.sig.ident == current_sig.ident && !current_erased {
             continue;
         }
+        let Some(margs) = reentry_margs(tf) else { continue };
         let fp = fingerprint_expr(
             decycle,
             &quote!(Self),
@@ -2057,13 +4007,15 @@ fn build_rule1_registrations(
             &self_targs,
             None,
         );
+        // A gated method's re-entry items come and go with it.
+        out.extend(quote!(#(for attr in cfg_attrs(&tf.attrs)) { #attr }));
         out.extend(emit_registration(
             decycle,
             &rt,
             &trait_.ident,
             &tf.sig.ident,
             &quote!(Self),
-            &self_targs,
+            &[&self_targs[..], &margs[..]].concat(),
             &[],
             fp,
         ));
@@ -2103,30 +4055,37 @@ fn build_bareparam_registrations(
     );
     let self_unsized = is_syntactically_unsized(&impl_.self_ty);
     let mut out = TokenStream::new();
-    let current_margs = type_const_idents(&current_sig.generics);
-    let fp = fingerprint_expr(
-        decycle,
-        &quote!(Self),
-        self_unsized,
-        &trait_.generics,
-        &self_targs,
-        Some(&current_sig.generics),
-    );
-    out.extend(emit_registration(
-        decycle,
-        &rt,
-        &trait_.ident,
-        &current_sig.ident,
-        &quote!(Self),
-        &self_targs,
-        &current_margs,
-        fp,
-    ));
+    // An erased method registers its erased instantiation with the siblings instead.
+    let current_erased = trait_.items.iter().any(|item| {
+        matches!(item, TraitItem::Fn(tf) if tf.sig.ident == current_sig.ident && !erased_method_params(tf).is_empty())
+    });
+    if !current_erased {
+        let current_margs = type_const_idents(&current_sig.generics);
+        let fp = fingerprint_expr(
+            decycle,
+            &quote!(Self),
+            self_unsized,
+            &trait_.generics,
+            &self_targs,
+            Some(&current_sig.generics),
+        );
+        out.extend(emit_registration(
+            decycle,
+            &rt,
+            &trait_.ident,
+            &current_sig.ident,
+            &quote!(Self),
+            &self_targs,
+            &current_margs,
+            fp,
+        ));
+    }
     for item in &trait_.items {
         let TraitItem::Fn(tf) = item else { continue };
-        if tf.sig.ident == current_sig.ident || method_is_generic(&tf.sig) {
+        if tf.sig.ident == current_sig.ident && !current_erased {
             continue;
         }
+        let Some(margs) = reentry_margs(tf) else { continue };
         let fp = fingerprint_expr(
             decycle,
             &quote!(Self),
@@ -2135,13 +4094,15 @@ fn build_bareparam_registrations(
             &self_targs,
             None,
         );
+        // A gated method's re-entry items come and go with it.
+        out.extend(quote!(#(for attr in cfg_attrs(&tf.attrs)) { #attr }));
         out.extend(emit_registration(
             decycle,
             &rt,
             &trait_.ident,
             &tf.sig.ident,
             &quote!(Self),
-            &self_targs,
+            &[&self_targs[..], &margs[..]].concat(),
             &[],
             fp,
         ));
@@ -2180,13 +4141,13 @@ fn build_shared_registrations(
                        // but keeping the sets aligned avoids a stray param on a no-op fn)
         }
         binders.extend(cb.binder.iter().cloned());
-        let target_unsized = is_syntactically_unsized(&cb.target);
-        let target_tokens = quote!(#{&cb.target});
+        // A forwarded `Box<B>: Cb` reaches `B`'s floor, not a `Box<B>` one.
+        let target = peel_forwarded(&cb.target, &cb.trait_ident, replacing_table);
+        let target_unsized = is_syntactically_unsized(&target);
+        let target_tokens = quote!(#target);
         for item in &sibling_trait.items {
             let TraitItem::Fn(tf) = item else { continue };
-            if method_is_generic(&tf.sig) {
-                continue;
-            }
+            let Some(margs) = reentry_margs(tf) else { continue };
             let fp = fingerprint_expr(
                 decycle,
                 &target_tokens,
@@ -2195,13 +4156,15 @@ fn build_shared_registrations(
                 &cb.targs,
                 None,
             );
+            // A gated method's re-entry items come and go with it.
+            out.extend(quote!(#(for attr in cfg_attrs(&tf.attrs)) { #attr }));
             out.extend(emit_registration(
                 decycle,
                 &rt,
                 &cb.trait_ident,
                 &tf.sig.ident,
                 &target_tokens,
-                &cb.targs,
+                &[&cb.targs[..], &margs[..]].concat(),
                 &[],
                 fp,
             ));
@@ -2210,6 +4173,127 @@ fn build_shared_registrations(
     (out, binders)
 }
 
+/// The re-entry registrations for the targets of a method's own cyclic bounds (`V: Ca`,
+/// `where Wrap<V>: Cb`), emitted from its Final entry (`emit_method_entries`) where they hold
+/// un-ranked — one set per instantiation of the method, keyed like the floors they serve. A
+/// maybe-unsized type param target and an HRTB bound are skipped.
+fn build_method_bound_registrations(
+    sig: &Signature,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    decycle: &Path,
+) -> TokenStream {
+    let rt = quote!(#{name!("shadowing_module")}::#{name!("ranked_traits")});
+    let inline = sig.generics.type_params().flat_map(|tp| {
+        let ident = &tp.ident;
+        let target: Type = parse_quote!(#ident);
+        tp.bounds.iter().map(move |b| (target.clone(), b))
+    });
+    let predicates = sig
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|wc| &wc.predicates)
+        .filter_map(|pred| match pred {
+            WherePredicate::Type(pt) if pt.lifetimes.is_none() => Some(pt),
+            _ => None,
+        })
+        .flat_map(|pt| pt.bounds.iter().map(move |b| (pt.bounded_ty.clone(), b)));
+    let mut out = TokenStream::new();
+    for (target, b) in inline.chain(predicates) {
+        let TypeParamBound::Trait(tb) = b else { continue };
+        let mut path = tb.path.clone();
+        strip_leading_self(&mut path);
+        if tb.lifetimes.is_some() || path.segments.len() != 1 {
+            continue;
+        }
+        let seg = &path.segments[0];
+        let Some((sibling_trait, _, _)) = replacing_table.get(&seg.ident) else {
+            continue;
+        };
+        if sig.generics.type_params().any(|tp| {
+            matches!(&target, Type::Path(TypePath { qself: None, path }) if path.is_ident(&tp.ident))
+                && type_param_is_maybe_unsized(tp, sig.generics.where_clause.as_ref())
+        }) {
+            continue;
+        }
+        let target = peel_forwarded(&target, &seg.ident, replacing_table);
+        let targs = nonlifetime_path_args(&seg.arguments);
+        for item in &sibling_trait.items {
+            let TraitItem::Fn(tf) = item else { continue };
+            let Some(margs) = reentry_margs(tf) else { continue };
+            let fp = fingerprint_expr(
+                decycle,
+                &quote!(#target),
+                is_syntactically_unsized(&target),
+                &sibling_trait.generics,
+                &targs,
+                None,
+            );
+            // A gated method's re-entry items come and go with it.
+            out.extend(quote!(#(for attr in cfg_attrs(&tf.attrs)) { #attr }));
+            out.extend(emit_registration(
+                decycle,
+                &rt,
+                &seg.ident,
+                &tf.sig.ident,
+                &quote!(#target),
+                &[&targs[..], &margs[..]].concat(),
+                &[],
+                fp,
+            ));
+        }
+    }
+    out
+}
+
+/// The Final impl's own copy of each method with a cyclic bound on its own generics
+/// (`has_method_generic_cyclic_bound`): the ranked method needs `V: CaRanked<..>`, which the
+/// Final impl — knowing only `V: Ca` — can't prove. So it runs the user's body with the bounds
+/// un-ranked, entering the other impls' Final impls like any caller outside the module. In
+/// unbounded mode it first registers the re-entries the floors below need for this
+/// instantiation: its own (`build_bareparam_registrations`) and its bounds' targets.
+fn emit_method_entries(
+    trait_: &ItemTrait,
+    impl_: &ItemImpl,
+    traits: &[ItemTrait],
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    decycle: Option<&Path>,
+) -> TokenStream {
+    let mut output = TokenStream::new();
+    for item in &impl_.items {
+        let ImplItem::Fn(ImplItemFn {
+            attrs,
+            vis,
+            defaultness,
+            sig,
+            block,
+        }) = item
+        else {
+            continue;
+        };
+        if !has_method_generic_cyclic_bound(sig, traits) {
+            continue;
+        }
+        let prologue = match decycle {
+            Some(decycle) => {
+                let mut prologue = build_bareparam_registrations(trait_, impl_, sig, decycle);
+                prologue.extend(build_method_bound_registrations(sig, replacing_table, decycle));
+                prologue
+            }
+            None => TokenStream::new(),
+        };
+        output.extend(quote! {
+            #(for attr in impl_.attrs.iter().filter(|attr| attr.path().is_ident("expect"))) { #attr }
+            #(for attr in attrs) { #attr }
+            #vis #defaultness #sig {
+                #prologue
+                #(for stmt in &block.stmts) { #stmt }
+            }
+        });
+    }
+    output
+}
+
 fn parse_comma_separated<T: Parse>(input: ParseStream) -> Result<Vec<T>> {
     let mut items = Vec::new();
     while !input.is_empty() {
@@ -2332,6 +4416,52 @@ pub fn ranked_trait_name(trait_ident: &Ident) -> Ident {
     name!("{}Ranked", trait_ident)
 }
 
+/// D1 bridge: the ident of a `#[decycle]` trait's rank-free types trait —
+/// `<trait_ident>Types<suffix>`, a sibling of [`ranked_trait_name`] in `ranked_traits`. Only
+/// emitted for a trait that declares associated types (see `assoc_type_idents`): it holds
+/// them, once, so `<T as Trait>::Assoc` names the same type at every rank. It is NOT a
+/// supertrait of the ranked trait, which declares no associated types: each ranked method
+/// reaches them under its own `where Self: XTypes` bound (see `process_trait_item_for_ranked`).
+/// A caller forwarding such a trait's items (a rank-preserving wrapper) implements it
+/// alongside the ranked trait, with the `type Assoc = ..;` items there.
+pub fn ranked_types_trait_name(trait_ident: &Ident) -> Ident {
+    name!("{}Types", trait_ident)
+}
+
+/// The original `#[decycle]` trait, re-exported under this name from `trait_objects_module_name`
+/// inside `ranked_traits`: a `dyn Trait` in a ranked copy names it (`TraitReplacer`), since the
+/// trait object is the same type at every rank, and the bare name is a dummy inside
+/// `shadowing_module`. Not glob-imported with the ranked traits, which would bring the
+/// original methods back into scope there.
+fn object_trait_name(trait_ident: &Ident) -> Ident {
+    name!("{}Object", trait_ident)
+}
+
+fn trait_objects_module_name() -> Ident {
+    name!("trait_objects")
+}
+
+/// `XTypes<args>`: the types trait of `trait_ident`, instantiated with the original trait's own
+/// (rank-free) `args`.
+fn types_trait_path(trait_ident: &Ident, args: &PathArguments) -> Path {
+    let mut path: Path = ranked_types_trait_name(trait_ident).into();
+    path.segments[0].arguments = args.clone();
+    path
+}
+
+/// The associated types a `#[decycle]` trait declares. Non-empty iff `finalize` emits the
+/// trait's types trait ([`ranked_types_trait_name`]).
+fn assoc_type_idents(trait_: &ItemTrait) -> Vec<Ident> {
+    trait_
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Type(t) => Some(t.ident.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 /// D1 bridge: the `mod` name `finalize` nests every SCC's ranked-trait machinery under
 /// (`#[doc(hidden)] mod #{name!("shadowing_module")}`, above).
 pub fn shadowing_module_name() -> Ident {
@@ -2475,7 +4605,11 @@ impl template_quote::ToTokens for AlsoRank {
 pub struct FinalizeArgs {
     pub working_list: Vec<Path>,
     pub traits: Vec<ItemTrait>,
+    /// The impls of `traits`, plus inherent impls whose bounds name one of them (see `fns`).
     pub contents: Vec<ItemImpl>,
+    /// Free fns whose bounds name one of `traits`. They, and `contents`' inherent impls, are
+    /// emitted as given, plus a ranked copy a cycle body calls instead (`emit_ranked_helpers`).
+    pub fns: Vec<ItemFn>,
     pub recurse_level: usize,
     pub support_infinite_cycle: bool,
     /// `(original_ident, local_alias)` pairs from this module's own
@@ -2492,6 +4626,25 @@ pub struct FinalizeArgs {
     pub decycle_path: Option<Path>,
 }
 
+/// No traits, at the macro's defaults (`recurse_level = 10`, `support_infinite_cycle = true`):
+/// a programmatic caller sets what it needs and takes the rest with `..Default::default()`,
+/// so a field added later doesn't break it.
+impl Default for FinalizeArgs {
+    fn default() -> Self {
+        FinalizeArgs {
+            working_list: Vec::new(),
+            traits: Vec::new(),
+            contents: Vec::new(),
+            fns: Vec::new(),
+            recurse_level: 10,
+            support_infinite_cycle: true,
+            renames: Vec::new(),
+            also_rank: Vec::new(),
+            decycle_path: None,
+        }
+    }
+}
+
 impl Parse for FinalizeArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let _crate_identity: LitStr = input.parse()?;
@@ -2549,10 +4702,19 @@ impl Parse for FinalizeArgs {
             parse_comma_separated::<AlsoRank>(&content)?
         };
 
+        let fns = if input.is_empty() {
+            Vec::new()
+        } else {
+            let content;
+            bracketed!(content in input);
+            parse_comma_separated::<ItemFn>(&content)?
+        };
+
         Ok(FinalizeArgs {
             working_list,
             traits,
             contents,
+            fns,
             recurse_level,
             support_infinite_cycle,
             renames,
@@ -2583,6 +4745,7 @@ impl template_quote::ToTokens for FinalizeArgs {
             })
             .collect();
         let also_rank = &self.also_rank;
+        let fns = &self.fns;
 
         tokens.extend(quote! {
             #crate_identity
@@ -2593,9 +4756,12 @@ impl template_quote::ToTokens for FinalizeArgs {
             #recurse_level
             #support_infinite_cycle
             [ #(#renames),* ]
-            #(if !also_rank.is_empty()) {
+            #(if !also_rank.is_empty() || !fns.is_empty()) {
                 { #(#also_rank),* }
             }
+            #(if !fns.is_empty()) {
+                [ #(#fns),* ]
+            }
         });
     }
 }
@@ -2686,164 +4852,1098 @@ fn replace_constraints(
                 path: type_param.ident.clone().into(),
             });
 
-            type_param.bounds = std::mem::take(&mut type_param.bounds)
-                .into_iter()
-                .filter_map(|bound| process_type_param_bound(&param_type, bound))
-                .collect();
+            type_param.bounds = std::mem::take(&mut type_param.bounds)
+                .into_iter()
+                .filter_map(|bound| process_type_param_bound(&param_type, bound))
+                .collect();
+
+            if type_param.bounds.is_empty() {
+                type_param.colon_token = None;
+            }
+        }
+    }
+
+    for pred in std::mem::take(&mut generics.where_clause)
+        .map(|wc| wc.predicates)
+        .into_iter()
+        .flatten()
+    {
+        match pred {
+            WherePredicate::Type(PredicateType {
+                lifetimes,
+                bounded_ty,
+                colon_token,
+                mut bounds,
+            }) => {
+                bounds = bounds
+                    .into_iter()
+                    .filter_map(|bound| process_type_param_bound(&bounded_ty, bound))
+                    .collect();
+
+                if !bounds.is_empty() {
+                    where_clause
+                        .predicates
+                        .push(WherePredicate::Type(PredicateType {
+                            lifetimes,
+                            bounded_ty,
+                            colon_token,
+                            bounds,
+                        }));
+                }
+            }
+            o => where_clause.predicates.push(o),
+        }
+    }
+
+    where_clause.predicates.extend(where_predicates_to_add);
+
+    generics.where_clause = (!where_clause.predicates.is_empty()).then_some(where_clause);
+}
+
+/// The ranked trait's supertraits: `trait_.supertraits` with every `#[decycle]` one rewritten
+/// to its ranked counterpart at the SAME rank (`trait Typed: Evaluate` ⇒ `TypedRanked<Rank>:
+/// EvaluateRanked<Rank>`), spelled bare — both are declared side by side in `ranked_traits`. A
+/// dummy-shadowed original supertrait would otherwise be unprovable for every ranked impl.
+fn ranked_supertraits(
+    trait_: &ItemTrait,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> Punctuated<TypeParamBound, Token![+]> {
+    let mut supertraits = trait_.supertraits.clone();
+    for bound in supertraits.iter_mut() {
+        let TypeParamBound::Trait(tb) = bound else {
+            continue;
+        };
+        if let Some(ranked) = ranked_supertrait_path(&tb.path, replacing_table, &parse_quote!(#{name!("Rank")})) {
+            tb.path = ranked;
+        }
+    }
+    supertraits
+}
+
+/// `XRanked<.., rank, ..>` for a single-segment `#[decycle]` trait path `X<..>` (keeping its
+/// arguments), or `None` for any other path.
+fn ranked_supertrait_path(
+    path: &Path,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    rank: &Type,
+) -> Option<Path> {
+    if path.leading_colon.is_some() || path.segments.len() != 1 {
+        return None;
+    }
+    let seg = &path.segments[0];
+    let (_, rank_loc, _) = replacing_table.get(&seg.ident)?;
+    let mut ranked: Path = ranked_trait_name(&seg.ident).into();
+    ranked.segments[0].arguments = seg.arguments.clone();
+    path_insert_type_arg(&mut ranked, *rank_loc, rank.clone());
+    Some(ranked)
+}
+
+/// `Self: #prefix SuperRanked<.., rank, ..>` for each `#[decycle]` supertrait of `trait_`,
+/// instantiated with `impl_`'s own trait arguments. Every leaf/inductive impl of the ranked
+/// trait must prove its ranked supertraits at its own rank (`ranked_supertraits`), and the
+/// impl's own cyclic bounds need not cover what the supertrait's impl needs — so it's required
+/// outright, and discharged rank for rank wherever the impl is used.
+fn decycle_supertrait_predicates(
+    trait_: &ItemTrait,
+    impl_: &ItemImpl,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    rank: &Type,
+    prefix: &TokenStream,
+) -> Vec<WherePredicate> {
+    decycle_supertrait_paths(trait_, impl_, replacing_table)
+        .into_iter()
+        .filter_map(|path| {
+            let ranked = ranked_supertrait_path(&path, replacing_table, rank)?;
+            Some(parse_quote!(Self: #prefix #ranked))
+        })
+        .collect()
+}
+
+/// The bare type parameter a blanket impl (`impl<T: Cb> Ca for T`) is for, if `impl_` is one.
+fn blanket_self_param(impl_: &ItemImpl) -> Option<&Ident> {
+    let Type::Path(TypePath { qself: None, path }) = &*impl_.self_ty else {
+        return None;
+    };
+    let ident = path.get_ident()?;
+    impl_
+        .generics
+        .params
+        .iter()
+        .any(|p| matches!(p, GenericParam::Type(tp) if &tp.ident == ident))
+        .then_some(ident)
+}
+
+/// Aborts on a blanket impl whose self parameter is bounded only by #[decycle] traits (`impl<T:
+/// Cb> Ca for T`) next to another impl of the same trait. Coherence proves the originals
+/// disjoint (`A: Cb` can't hold), but not the ranked copies: `T: CbRanked<Rank>` leaves `Rank`
+/// free, and a downstream crate could implement `CbRanked<Local>` for `A` — so every rank of the
+/// pair would be E0119. Any other trait bound on `T` (`T: Cb + Leaf`) keeps them apart.
+fn reject_overlapping_blanket_impls(
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    traits: &[ItemTrait],
+) {
+    for (trait_ident, (_, _, impls)) in replacing_table {
+        for impl_ in impls {
+            let Some(self_param) = blanket_self_param(impl_) else {
+                continue;
+            };
+            let param_bounds = impl_.generics.type_params().filter(|tp| &tp.ident == self_param).flat_map(|tp| &tp.bounds);
+            let where_bounds = impl_.generics.where_clause.iter().flat_map(|wc| &wc.predicates).flat_map(|pred| match pred {
+                WherePredicate::Type(pt) if matches!(&pt.bounded_ty, Type::Path(TypePath { qself: None, path }) if path.is_ident(self_param)) => {
+                    Some(&pt.bounds)
+                }
+                _ => None,
+            });
+            let trait_bounds: Vec<&TypeParamBound> = param_bounds
+                .chain(where_bounds.flatten())
+                .filter(|b| matches!(b, TypeParamBound::Trait(tb) if matches!(tb.modifier, TraitBoundModifier::None)))
+                .collect();
+            let cyclic_only = !trait_bounds.is_empty() && trait_bounds.iter().all(|b| is_cyclic_bound(b, traits));
+            if cyclic_only && impls.len() > 1 {
+                abort!(
+                    impl_.self_ty,
+                    "decycle: a blanket impl of `{}` bounded only by #[decycle] traits can't sit next to another impl of `{}`",
+                    trait_ident, trait_ident;
+                    note = "rustc can't prove the ranked copies disjoint: `{}`'s ranked bound leaves the rank free, so it might hold for the other impls' self types (E0119)", self_param;
+                    hint = "bound `{}` by a trait the other self types don't implement as well (`{}: Cb + Leaf`)", self_param, self_param
+                );
+            }
+        }
+    }
+}
+
+/// `T: CbRanked<.., (), ..>` for each cyclic bound on a blanket impl's own self parameter.
+/// `remove_cyclic_bounds` leaves the `()` leaf of `impl<T: Cb> Ca for T` with no bound on `T`
+/// at all — overlapping every sibling's leaf (`impl CaRanked<()> for A`). Keeping the bound,
+/// at the floor rank, restores the same non-overlap the original impls have.
+fn blanket_floor_predicates(
+    impl_: &ItemImpl,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> Vec<WherePredicate> {
+    let Some(self_param) = blanket_self_param(impl_) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    replace_constraints(&mut impl_.generics.clone(), |ty, mut trait_path| {
+        if matches!(&ty, Type::Path(TypePath { qself: None, path }) if path.is_ident(self_param)) {
+            strip_leading_self(&mut trait_path);
+            if let Some(ranked) = ranked_supertrait_path(&trait_path, replacing_table, &floor_rank()) {
+                out.push(parse_quote!(#ty: #ranked));
+            }
+        }
+        Some((ty, trait_path))
+    });
+    out
+}
+
+/// The inductive impl's counterpart of `decycle_supertrait_predicates`. Requiring `Self:
+/// SuperRanked<(Rank,)>` outright there would sit next to the `Self: SuperRanked<Rank>` the
+/// impl's own `Self: XRanked<Rank>` bound elaborates to, making a plain `self.base()` call in
+/// the body ambiguous between the two ranks. So when the supertrait impl for the same self
+/// type is in the batch (matched syntactically, like `reachable_side_bounds_ok`), its own
+/// where-clause is inherited instead — un-ranked, `TraitReplacer` ranks it along with the
+/// rest — which proves `Self: SuperRanked<(Rank,)>` through that impl. The explicit bound is
+/// only the fallback.
+fn inherited_supertrait_predicates(
+    trait_: &ItemTrait,
+    impl_: &ItemImpl,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+    prefix: &TokenStream,
+) -> Vec<WherePredicate> {
+    let self_ty = &impl_.self_ty;
+    let self_ty = quote!(#self_ty).to_string();
+    let mut out = Vec::new();
+    for path in decycle_supertrait_paths(trait_, impl_, replacing_table) {
+        let seg = &path.segments[0];
+        let wanted = &seg.arguments;
+        let wanted = quote!(#wanted).to_string();
+        let (_, _, super_impls) = &replacing_table[&seg.ident];
+        let found = super_impls.iter().find(|super_impl| {
+            let super_self = &super_impl.self_ty;
+            let args = &super_impl.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments;
+            quote!(#super_self).to_string() == self_ty && quote!(#args).to_string() == wanted
+        });
+        match found {
+            Some(super_impl) => {
+                let mut g = super_impl.generics.clone();
+                let types_bounds = types_where_predicates(&g, replacing_table, prefix);
+                // Param-inline bounds (`impl<T: Base>`) as predicates too.
+                replace_constraints(&mut g, |ty, path| Some((ty, path)));
+                out.extend(g.where_clause.into_iter().flat_map(|w| w.predicates));
+                out.extend(types_bounds);
+            }
+            None => {
+                let rank = parse_quote!((#{name!("Rank")},));
+                if let Some(ranked) = ranked_supertrait_path(&path, replacing_table, &rank) {
+                    out.push(parse_quote!(Self: #prefix #ranked));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// `trait_`'s `#[decycle]` supertrait paths, instantiated with `impl_`'s own trait arguments.
+fn decycle_supertrait_paths(
+    trait_: &ItemTrait,
+    impl_: &ItemImpl,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> Vec<Path> {
+    let impl_args = nonlifetime_path_args(
+        &impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments,
+    );
+    let subst: HashMap<Ident, Type> = trait_
+        .generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            GenericParam::Type(t) => Some(t.ident.clone()),
+            GenericParam::Const(c) => Some(c.ident.clone()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .zip(impl_args)
+        .filter_map(|(ident, arg)| match arg {
+            GenericArgument::Type(ty) => Some((ident, ty)),
+            _ => None,
+        })
+        .collect();
+    trait_
+        .supertraits
+        .iter()
+        .filter_map(|bound| {
+            let TypeParamBound::Trait(tb) = bound else {
+                return None;
+            };
+            if tb.path.leading_colon.is_some()
+                || tb.path.segments.len() != 1
+                || !replacing_table.contains_key(&tb.path.segments[0].ident)
+            {
+                return None;
+            }
+            match apply_type_subst(
+                &Type::Path(TypePath {
+                    qself: None,
+                    path: tb.path.clone(),
+                }),
+                &subst,
+            ) {
+                Type::Path(TypePath { path, .. }) => Some(path),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// A wrapper named in `#[decycle(forward(...))]` on a `#[decycle]` trait: the trait is
+/// implemented for the wrapper by forwarding to its pointee, at every rank.
+#[derive(Clone)]
+pub(crate) enum ForwardWrapper {
+    /// `&T` or `&mut T`.
+    Ref(Option<Token![mut]>),
+    /// `Box`, `Rc`, `Arc`, or another single-parameter `Deref` wrapper, named without
+    /// arguments.
+    Path(Path),
+}
+
+impl Parse for ForwardWrapper {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![&]) {
+            input.parse::<Token![&]>()?;
+            Ok(ForwardWrapper::Ref(input.parse()?))
+        } else {
+            Ok(ForwardWrapper::Path(Path::parse_mod_style(input)?))
+        }
+    }
+}
+
+impl ForwardWrapper {
+    fn last_ident_is(&self, names: &[&str]) -> bool {
+        match self {
+            ForwardWrapper::Ref(_) => false,
+            ForwardWrapper::Path(path) => {
+                let ident = &path.segments.last().unwrap().ident;
+                names.iter().any(|n| ident == n)
+            }
+        }
+    }
+
+    /// The wrapper around `inner`; a reference borrows for `lt`.
+    fn wrap(&self, inner: &Type, lt: &Lifetime) -> Type {
+        match self {
+            ForwardWrapper::Ref(mutability) => parse_quote!(&#lt #mutability #inner),
+            ForwardWrapper::Path(path) => {
+                let mut path = path.clone();
+                path_insert_type_arg(&mut path, 0, inner.clone());
+                Type::Path(TypePath { qself: None, path })
+            }
+        }
+    }
+
+    /// Whether the pointee may be unsized. Only known for references and the std pointers; an
+    /// arbitrary wrapper is assumed to require `Sized`.
+    fn allows_unsized(&self) -> bool {
+        matches!(self, ForwardWrapper::Ref(_)) || self.last_ident_is(&["Box", "Rc", "Arc"])
+    }
+
+    /// Whether a `&mut self` method can reach the pointee. A path wrapper other than `Rc` and
+    /// `Arc` is taken to be `DerefMut`, which the forwarded call checks (`emit_forwarding_items`).
+    fn allows_mut(&self) -> bool {
+        match self {
+            ForwardWrapper::Ref(mutability) => mutability.is_some(),
+            ForwardWrapper::Path(_) => !self.last_ident_is(&["Rc", "Arc"]),
+        }
+    }
+}
+
+impl template_quote::ToTokens for ForwardWrapper {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            ForwardWrapper::Ref(mutability) => tokens.extend(quote!(& #mutability)),
+            ForwardWrapper::Path(path) => tokens.extend(quote!(#path)),
+        }
+    }
+}
+
+/// The arguments `#[decycle]` takes on a trait inside a `#[decycle]` module.
+#[derive(Default)]
+pub(crate) struct TraitOptions {
+    /// `forward(Box, Rc, &, ...)`: see `emit_forwarding_impls`.
+    pub forward: Vec<ForwardWrapper>,
+    /// `dyn`: see `dyn_entry_defaults`.
+    pub dyn_entries: bool,
+}
+
+/// Parses `#[decycle(dyn, forward(Box, Rc, &, ...))]`.
+pub(crate) fn parse_trait_options(attr: &Attribute) -> TraitOptions {
+    syn::custom_keyword!(forward);
+    attr.parse_args_with(|input: ParseStream| {
+        let mut options = TraitOptions::default();
+        while !input.is_empty() {
+            if input.parse::<Option<Token![dyn]>>()?.is_some() {
+                options.dyn_entries = true;
+            } else {
+                input.parse::<forward>()?;
+                let content;
+                parenthesized!(content in input);
+                options
+                    .forward
+                    .extend(content.parse_terminated(ForwardWrapper::parse, Token![,])?);
+            }
+            if input.parse::<Token![,]>().is_err() {
+                break;
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens"));
+        }
+        Ok(options)
+    })
+    .unwrap_or_else(|e| {
+        abort!(
+            e.span(), "{}", e;
+            hint = "a #[decycle] trait accepts `#[decycle(dyn, forward(Box, Rc, Arc, &, &mut), expand_first)]`"
+        )
+    })
+}
+
+/// The options of the `#[decycle(...)]` attribute `process_module` left on a carried trait
+/// (it's never emitted: only the ranked and types traits are declared here).
+fn trait_options(trait_: &ItemTrait) -> TraitOptions {
+    trait_
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("decycle"))
+        .map(parse_trait_options)
+        .fold(TraitOptions::default(), |mut acc, options| {
+            acc.forward.extend(options.forward);
+            acc.dyn_entries |= options.dyn_entries;
+            acc
+        })
+}
+
+fn forward_wrappers(trait_: &ItemTrait) -> Vec<ForwardWrapper> {
+    trait_options(trait_).forward
+}
+
+/// Whether a trait object can dispatch `sig`, so a `#[decycle(dyn)]` trait gives it an entry
+/// (`dyn_entry_name`): a `&self` or `&mut self` method without type or const params, and
+/// without `Self` or `impl Trait` in the rest of its signature.
+fn is_dyn_dispatchable(sig: &Signature) -> bool {
+    fn mentions(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(ident) => ident == "Self" || ident == "impl",
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream()),
+            _ => false,
+        })
+    }
+    let Some(FnArg::Receiver(receiver)) = sig.inputs.first() else {
+        return false;
+    };
+    receiver.reference.is_some()
+        && receiver.colon_token.is_none()
+        && sig.asyncness.is_none()
+        && sig.constness.is_none()
+        && sig.variadic.is_none()
+        && sig.generics.params.iter().all(|p| matches!(p, GenericParam::Lifetime(_)))
+        && !mentions(quote! {
+            #(for arg in sig.inputs.iter().skip(1)) { #arg }
+            #{&sig.output}
+            #{&sig.generics.where_clause}
+        })
+}
+
+/// The entry of a `#[decycle(dyn)]` trait's method `m`: `m` with a leading `usize` argument,
+/// the depth of the rank its caller is at (`__RankDepth`), so that a call through a vtable
+/// continues at that rank. A ranked body calls it instead of `m` (`RouteDynCalls`).
+fn dyn_entry_name(method: &Ident) -> Ident {
+    name!("__{}_dyn", method)
+}
+
+/// `sig` with its arguments bound to `__argN`, and those idents.
+fn bind_args(sig: &Signature) -> (Signature, Vec<Ident>) {
+    let mut sig = sig.clone();
+    let mut args = Vec::new();
+    for (i, arg) in sig.inputs.iter_mut().enumerate() {
+        if let FnArg::Typed(pat_type) = arg {
+            let ident = Ident::new(&format!("__arg{}", i), Span::call_site());
+            *pat_type.pat = Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: ident.clone(),
+                subpat: None,
+            });
+            args.push(ident);
+        }
+    }
+    (sig, args)
+}
+
+/// The signature of `sig`'s entry, with the depth argument bound to `depth`.
+fn dyn_entry_sig(sig: &Signature, depth: TokenStream) -> (Signature, Vec<Ident>) {
+    let (mut sig, args) = bind_args(sig);
+    sig.ident = dyn_entry_name(&sig.ident);
+    sig.inputs.insert(1, parse_quote!(#depth: usize));
+    (sig, args)
+}
+
+/// `expr`, in an `unsafe` block for an `unsafe fn`.
+fn maybe_unsafe(sig: &Signature, expr: TokenStream) -> TokenStream {
+    if sig.unsafety.is_some() {
+        quote!(#[allow(unused_unsafe)] unsafe { #expr })
+    } else {
+        expr
+    }
+}
+
+/// The entries of a `#[decycle(dyn)]` trait (`dyn_entry_name`), each defaulting to its method:
+/// on the trait as emitted, for an impl outside the module, and on the ranked trait, for a
+/// ranked impl reached through a routed call.
+pub(crate) fn dyn_entry_defaults(trait_: &ItemTrait) -> Vec<TraitItem> {
+    trait_
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(f) if is_dyn_dispatchable(&f.sig) => {
+                let (sig, args) = dyn_entry_sig(&f.sig, quote!(_));
+                let call = maybe_unsafe(&sig, quote!(Self::#{&f.sig.ident}(self, #(#args),*)));
+                let mut attrs: Vec<_> = f
+                    .attrs
+                    .iter()
+                    .filter(|attr| {
+                        ["cfg", "must_use", "deprecated"].iter().any(|name| attr.path().is_ident(name))
+                    })
+                    .cloned()
+                    .collect();
+                name_must_use(&mut attrs, &trait_.ident, &f.sig.ident);
+                // A call routed to the entry lints like one of the method, and (like every
+                // layer decycle adds) passes its caller's location on.
+                Some(parse_quote! {
+                    #(for attr in &attrs) { #attr }
+                    #[doc(hidden)]
+                    #{track_caller_attr(&sig)}
+                    #[allow(deprecated)]
+                    #sig {
+                        #call
+                    }
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Final impl's overrides of a `#[decycle(dyn)]` trait's entries, and the bounds they need.
+/// Each continues at its caller's depth, down to the floor — where unbounded mode re-enters at
+/// the initial rank instead.
+fn emit_dyn_entries(
+    trait_: &ItemTrait,
+    impl_: &ItemImpl,
+    rank_loc: usize,
+    recurse_level: usize,
+    support_infinite_cycle: bool,
+) -> (Vec<WherePredicate>, TokenStream) {
+    if !trait_options(trait_).dyn_entries {
+        return (Vec::new(), TokenStream::new());
+    }
+    let trait_args = impl_.trait_.as_ref().unwrap().1.ty_generics();
+    let ranked = |depth: usize| -> Path {
+        parse_quote!(
+            #{name!("shadowing_module")}::#{name!("ranked_traits")}::#{ranked_trait_name(&trait_.ident)}
+            #{trait_args.insert(rank_loc, initial_rank(depth))}
+        )
+    };
+    let depths: Vec<usize> = (usize::from(support_infinite_cycle)..recurse_level).collect();
+    let bounds = depths
+        .iter()
+        .map(|depth| parse_quote!(Self: #{ranked(*depth)}))
+        .collect();
+    let initial = ranked(recurse_level);
+    let entries = trait_.items.iter().filter_map(|item| match item {
+        TraitItem::Fn(f) if is_dyn_dispatchable(&f.sig) => {
+            let method = &f.sig.ident;
+            let depth = Ident::new("__depth", Span::call_site());
+            let (sig, args) = if depths.is_empty() {
+                dyn_entry_sig(&f.sig, quote!(_))
+            } else {
+                dyn_entry_sig(&f.sig, quote!(#depth))
+            };
+            let body = quote! {
+                #(if depths.is_empty()) {
+                    <Self as #initial>::#method(self, #(#args),*)
+                }
+                #(else) {
+                    match #depth {
+                        #(for d in &depths) {
+                            #d => <Self as #{ranked(*d)}>::#method(self, #(#args),*),
+                        }
+                        _ => <Self as #initial>::#method(self, #(#args),*),
+                    }
+                }
+            };
+            let body = maybe_unsafe(&sig, body);
+            Some(quote! {
+                #(for attr in cfg_attrs(&f.attrs)) { #attr }
+                #{track_caller_attr(&sig)}
+                #[inline]
+                #[allow(deprecated)]
+                #sig {
+                    #body
+                }
+            })
+        }
+        _ => None,
+    });
+    (bounds, quote!(#(#entries)*))
+}
+
+/// `impl XRanked<Rank> for dyn X` at every rank, in `ranked_traits`, for a `#[decycle(dyn)]`
+/// trait all of whose items are dispatchable methods and that has no `#[decycle]` supertrait:
+/// each method calls its entry, which the vtable resolves to the Final impl of the concrete
+/// type, at the same rank. A `dyn X: X` bound in a ranked copy then holds at any rank.
+fn emit_dyn_object_impl(trait_: &ItemTrait, rank_loc: usize, traits: &[ItemTrait], decycle: &Path) -> TokenStream {
+    let dispatchable = trait_
+        .items
+        .iter()
+        .all(|item| matches!(item, TraitItem::Fn(f) if is_dyn_dispatchable(&f.sig)));
+    let cyclic_supertrait = trait_.supertraits.iter().any(|b| is_cyclic_bound(b, traits));
+    if !trait_options(trait_).dyn_entries || !dispatchable || cyclic_supertrait {
+        return TokenStream::new();
+    }
+    let rank = name!("Rank");
+    let lt: Lifetime = parse_quote!('__decycle_dyn);
+    let object: Type = parse_quote!(
+        dyn #{trait_objects_module_name()}::#{object_trait_name(&trait_.ident)}
+        #{trait_.generics.ty_generics()} + #lt
+    );
+    let methods = trait_.items.iter().filter_map(|item| match item {
+        TraitItem::Fn(f) => {
+            let (sig, args) = bind_args(&f.sig);
+            let object_trait = quote!(
+                #{trait_objects_module_name()}::#{object_trait_name(&trait_.ident)}
+                #{trait_.generics.ty_generics()}
+            );
+            let call = maybe_unsafe(&sig, quote! {
+                <#object as #object_trait>::#{dyn_entry_name(&f.sig.ident)}(
+                    self,
+                    <#rank as #decycle::__RankDepth>::DEPTH,
+                    #(#args),*
+                )
+            });
+            Some(quote! {
+                #(for attr in cfg_attrs(&f.attrs)) { #attr }
+                #{track_caller_attr(&sig)}
+                #[allow(deprecated)]
+                #sig { #call }
+            })
+        }
+        _ => None,
+    });
+    quote! {
+        #(for attr in cfg_attrs(&trait_.attrs)) { #attr }
+        // A non-Rust ABI method takes `&dyn X` here, which isn't FFI-safe.
+        #[allow(unused, improper_ctypes_definitions)]
+        #{&trait_.unsafety} impl<#lt, #(for p in &trait_.generics.params) { #{generic_param_bounded(p)}, } #rank: #decycle::__RankDepth>
+        #{ranked_trait_name(&trait_.ident)} #{trait_.generics.ty_generics().insert(rank_loc, parse_quote!(#rank))}
+        for #object #{&trait_.generics.where_clause}
+        {
+            #(#methods)*
+        }
+    }
+}
+
+/// Routes each call of a `#[decycle(dyn)]` trait's method on a trait object in a ranked body
+/// through its entry (`recv.eval(..)` → `recv.__eval_dyn(depth, ..)`), passing the depth of the
+/// body's rank: the object then continues at that rank, where the vtable would reset it.
+///
+/// The receiver's type isn't known here, so only a receiver that is syntactically an object of
+/// that trait is routed: a binding declared `&dyn Node` / `Box<dyn Node>` (a fn or closure
+/// parameter, a typed `let`), `self` in an impl for `dyn Node`, or an `as &dyn Node` cast —
+/// each possibly behind `&`, `*` or parentheses. Any other call keeps its ranked resolution.
+struct RouteDynCalls {
+    /// Each `#[decycle(dyn)]` trait's dispatchable methods.
+    methods: HashMap<Ident, Vec<Ident>>,
+    depth: Expr,
+    /// The rank param the depth is read from (`rank_depth_predicate`).
+    rank: Ident,
+    /// The object trait of each binding in scope (innermost last), `None` where a binding
+    /// shadows one with an untyped or non-object pattern.
+    scopes: Vec<HashMap<Ident, Option<Ident>>>,
+}
 
-            if type_param.bounds.is_empty() {
-                type_param.colon_token = None;
-            }
+impl RouteDynCalls {
+    fn new(traits: &[ItemTrait], rank: &Ident, decycle: &Path) -> Self {
+        RouteDynCalls {
+            methods: traits
+                .iter()
+                .filter(|t| trait_options(t).dyn_entries)
+                .map(|t| {
+                    let methods = t
+                        .items
+                        .iter()
+                        .filter_map(|item| match item {
+                            TraitItem::Fn(f) if is_dyn_dispatchable(&f.sig) => Some(f.sig.ident.clone()),
+                            _ => None,
+                        })
+                        .collect();
+                    (t.ident.clone(), methods)
+                })
+                .collect(),
+            depth: parse_quote!(<#rank as #decycle::__RankDepth>::DEPTH),
+            rank: rank.clone(),
+            scopes: vec![HashMap::new()],
         }
     }
 
-    for pred in std::mem::take(&mut generics.where_clause)
-        .map(|wc| wc.predicates)
-        .into_iter()
-        .flatten()
-    {
-        match pred {
-            WherePredicate::Type(PredicateType {
-                lifetimes,
-                bounded_ty,
-                colon_token,
-                mut bounds,
-            }) => {
-                bounds = bounds
-                    .into_iter()
-                    .filter_map(|bound| process_type_param_bound(&bounded_ty, bound))
-                    .collect();
+    /// `Rank: __RankDepth`, if the batch has a `#[decycle(dyn)]` trait. Every ranked body
+    /// requires it, not only those with a routed call: a body reaching a routed helper or impl
+    /// at its own rank must prove it there.
+    fn rank_depth_predicate(&self, decycle: &Path) -> Option<WherePredicate> {
+        let rank = &self.rank;
+        (!self.methods.is_empty()).then(|| parse_quote!(#rank: #decycle::__RankDepth))
+    }
 
-                if !bounds.is_empty() {
-                    where_clause
-                        .predicates
-                        .push(WherePredicate::Type(PredicateType {
-                            lifetimes,
-                            bounded_ty,
-                            colon_token,
-                            bounds,
-                        }));
+    /// Routes the calls in a free fn's `block`, with `sig`'s parameters in scope.
+    fn route_fn(&mut self, sig: &Signature, block: &mut Block) {
+        self.scopes.push(HashMap::new());
+        for input in &sig.inputs {
+            if let FnArg::Typed(pt) = input {
+                self.bind(&pt.pat, Some(&pt.ty));
+            }
+        }
+        syn::visit_mut::VisitMut::visit_block_mut(self, block);
+        self.scopes.pop();
+    }
+
+    /// The `#[decycle(dyn)]` trait `ty` is an object of, behind any references or `Box`es.
+    fn object_trait(&self, ty: &Type) -> Option<Ident> {
+        match ty {
+            Type::Reference(r) => self.object_trait(&r.elem),
+            Type::Paren(p) => self.object_trait(&p.elem),
+            Type::Group(g) => self.object_trait(&g.elem),
+            Type::TraitObject(obj) => obj.bounds.iter().find_map(|b| match b {
+                TypeParamBound::Trait(tb) => {
+                    let ident = &tb.path.segments.last()?.ident;
+                    self.methods.contains_key(ident).then(|| ident.clone())
+                }
+                _ => None,
+            }),
+            Type::Path(TypePath { qself: None, path }) => {
+                let last = path.segments.last()?;
+                let PathArguments::AngleBracketed(ab) = &last.arguments else {
+                    return None;
+                };
+                match (last.ident == "Box", ab.args.first()) {
+                    (true, Some(GenericArgument::Type(inner))) if ab.args.len() == 1 => self.object_trait(inner),
+                    _ => None,
                 }
             }
-            o => where_clause.predicates.push(o),
+            _ => None,
         }
     }
 
-    where_clause.predicates.extend(where_predicates_to_add);
+    /// Records the bindings `pat` introduces, of type `ty` if it is one.
+    fn bind(&mut self, pat: &Pat, ty: Option<&Type>) {
+        match pat {
+            Pat::Type(pt) => self.bind(&pt.pat, Some(&pt.ty)),
+            Pat::Ident(pi) if pi.subpat.is_none() => {
+                let object = ty.and_then(|ty| self.object_trait(ty));
+                self.scopes.last_mut().unwrap().insert(pi.ident.clone(), object);
+            }
+            // Anything a nested pattern binds shadows an outer object binding.
+            _ => {
+                struct Idents(Vec<Ident>);
+                impl<'ast> syn::visit::Visit<'ast> for Idents {
+                    fn visit_pat_ident(&mut self, pi: &'ast PatIdent) {
+                        self.0.push(pi.ident.clone());
+                        syn::visit::visit_pat_ident(self, pi);
+                    }
+                }
+                let mut idents = Idents(Vec::new());
+                syn::visit::Visit::visit_pat(&mut idents, pat);
+                let scope = self.scopes.last_mut().unwrap();
+                scope.extend(idents.0.into_iter().map(|ident| (ident, None)));
+            }
+        }
+    }
 
-    generics.where_clause = (!where_clause.predicates.is_empty()).then_some(where_clause);
+    /// The `#[decycle(dyn)]` trait the receiver `expr` is syntactically an object of.
+    fn receiver_trait(&self, expr: &Expr) -> Option<Ident> {
+        match expr {
+            Expr::Paren(e) => self.receiver_trait(&e.expr),
+            Expr::Group(e) => self.receiver_trait(&e.expr),
+            Expr::Reference(e) => self.receiver_trait(&e.expr),
+            Expr::Unary(ExprUnary { op: UnOp::Deref(_), expr, .. }) => self.receiver_trait(expr),
+            Expr::Cast(e) => self.object_trait(&e.ty),
+            Expr::Path(ExprPath { qself: None, path, .. }) => {
+                let ident = path.get_ident()?;
+                self.scopes.iter().rev().find_map(|scope| scope.get(ident)).cloned().flatten()
+            }
+            _ => None,
+        }
+    }
 }
 
-fn is_self_type(ty: &Type) -> bool {
-    matches!(ty, Type::Path(TypePath { qself: None, path }) if path.is_ident("Self"))
-}
+impl syn::visit_mut::VisitMut for RouteDynCalls {
+    fn visit_item_impl_mut(&mut self, impl_: &mut ItemImpl) {
+        let self_object = self.object_trait(&impl_.self_ty);
+        self.scopes.push(HashMap::from([(Ident::new("self", Span::call_site()), self_object)]));
+        syn::visit_mut::visit_item_impl_mut(self, impl_);
+        self.scopes.pop();
+    }
 
-/// Whether `ty` contains `Self` ANYWHERE in its structure, not just as the whole type —
-/// `type Assoc = Box<Self>;` / `(Self,)` are just as cyclic as a bare `type Assoc = Self;`
-/// (`is_self_type` alone only catches the latter).
-fn type_contains_self(ty: &Type) -> bool {
-    struct FindSelf(bool);
-    impl<'ast> syn::visit::Visit<'ast> for FindSelf {
-        fn visit_type(&mut self, ty: &'ast Type) {
-            if is_self_type(ty) {
-                self.0 = true;
-            }
-            syn::visit::visit_type(self, ty);
+    fn visit_impl_item_fn_mut(&mut self, f: &mut ImplItemFn) {
+        let sig = f.sig.clone();
+        self.route_fn(&sig, &mut f.block);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        self.scopes.push(HashMap::new());
+        syn::visit_mut::visit_block_mut(self, block);
+        self.scopes.pop();
+    }
+
+    fn visit_local_mut(&mut self, local: &mut Local) {
+        // The initializer is in the outer binding's scope.
+        if let Some(init) = &mut local.init {
+            syn::visit_mut::visit_local_init_mut(self, init);
         }
+        self.bind(&local.pat, None);
     }
-    let mut finder = FindSelf(false);
-    syn::visit::Visit::visit_type(&mut finder, ty);
-    finder.0
-}
 
-/// Check for `type Assoc = Self;` in impl blocks where the trait's associated type
-/// has a bound referencing a `#[decycle]` trait. This creates an infinite recursive
-/// definition because the ranked trait's associated type bound refers to the original
-/// trait, causing a cycle through the Final impl.
-fn check_assoc_type_self(
-    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
-) {
-    let decycle_idents: std::collections::HashSet<&Ident> = replacing_table.keys().collect();
+    fn visit_expr_closure_mut(&mut self, closure: &mut ExprClosure) {
+        self.scopes.push(HashMap::new());
+        for input in &closure.inputs {
+            self.bind(input, None);
+        }
+        self.visit_expr_mut(&mut closure.body);
+        self.scopes.pop();
+    }
 
-    for (trait_, _, impls) in replacing_table.values() {
-        for trait_item in &trait_.items {
-            let TraitItem::Type(TraitItemType {
-                ident: assoc_ident,
-                bounds,
-                ..
-            }) = trait_item
-            else {
-                continue;
-            };
+    fn visit_expr_method_call_mut(&mut self, call: &mut ExprMethodCall) {
+        let routed = call.turbofish.is_none()
+            && self
+                .receiver_trait(&call.receiver)
+                .is_some_and(|t| self.methods[&t].contains(&call.method));
+        if routed {
+            call.method = dyn_entry_name(&call.method);
+            call.args.insert(0, self.depth.clone());
+        }
+        syn::visit_mut::visit_expr_method_call_mut(self, call);
+    }
+}
 
-            // Check if any bound on this associated type references a decycle trait.
-            // Single-segment match only — a multi-segment path (`some::mod::Foo`) is a
-            // DIFFERENT item that merely shares a last segment with a decycle trait.
-            let has_decycle_bound = bounds.iter().any(|bound| {
-                if let TypeParamBound::Trait(TraitBound { path, .. }) = bound {
-                    if path.segments.len() == 1 {
-                        return decycle_idents.contains(&path.segments[0].ident);
+/// `ty` with every wrapper `trait_ident` forwards through peeled off (`Box<Rc<Expr>>` ⇒
+/// `Expr`): the forwarding impls hold for any pointee, so it's the pointee's impl that a
+/// `Box<Expr>: Trait` bound reaches.
+fn peel_forwarded(
+    ty: &Type,
+    trait_ident: &Ident,
+    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
+) -> Type {
+    let Some((trait_, _, _)) = replacing_table.get(trait_ident) else {
+        return ty.clone();
+    };
+    let wrappers = forward_wrappers(trait_);
+    let mut ty = ty.clone();
+    'peel: loop {
+        for wrapper in &wrappers {
+            let inner = match (wrapper, &ty) {
+                (ForwardWrapper::Ref(m), Type::Reference(r))
+                    if m.is_some() == r.mutability.is_some() =>
+                {
+                    Some((*r.elem).clone())
+                }
+                (
+                    ForwardWrapper::Path(path),
+                    Type::Path(TypePath {
+                        qself: None,
+                        path: ty_path,
+                    }),
+                ) => {
+                    let last = ty_path.segments.last().unwrap();
+                    match &last.arguments {
+                        PathArguments::AngleBracketed(ab)
+                            if last.ident == path.segments.last().unwrap().ident
+                                && ab.args.len() == 1 =>
+                        {
+                            match &ab.args[0] {
+                                GenericArgument::Type(inner) => Some(inner.clone()),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
                     }
                 }
-                false
-            });
-
-            if !has_decycle_bound {
-                continue;
+                _ => None,
+            };
+            if let Some(inner) = inner {
+                ty = inner;
+                continue 'peel;
             }
+        }
+        return ty;
+    }
+}
 
-            // Check impls for a `Self`-containing assignment (`= Self;`, `= Box<Self>;`,
-            // `= (Self,);`, …) — any of them recreate the same cycle through the Final
-            // impl, not just the bare-`Self` case.
-            for impl_ in impls {
-                for impl_item in &impl_.items {
-                    let ImplItem::Type(ImplItemType { ident, ty, .. }) = impl_item else {
-                        continue;
-                    };
-                    if ident != assoc_ident {
-                        continue;
+/// The items of a forwarding impl for `wrapper`: each method calls `#path::method` on the
+/// pointee, each associated type and const names `#types_path`'s / `#path`'s.
+fn emit_forwarding_items<'a>(
+    items: impl Iterator<Item = &'a TraitItem>,
+    wrapper: &ForwardWrapper,
+    path: &TokenStream,
+    types_path: &TokenStream,
+) -> TokenStream {
+    let mut output = TokenStream::new();
+    for item in items {
+        match item {
+            TraitItem::Fn(TraitItemFn { attrs, sig, .. }) => {
+                let mut sig = sig.clone();
+                let Some(receiver) = sig.receiver() else {
+                    abort!(
+                        sig.ident,
+                        "`{}` has no `self` receiver, so it can't be forwarded through `{}`",
+                        sig.ident,
+                        quote!(#wrapper).to_string().replace(' ', "")
+                    )
+                };
+                let pointee = match &*receiver.ty {
+                    Type::Reference(TypeReference { mutability: None, elem, .. })
+                        if matches!(&**elem, Type::Path(p) if p.path.is_ident("Self")) =>
+                    {
+                        quote!(&**self)
                     }
-                    if type_contains_self(ty) {
-                        abort!(
-                            ty,
-                            "infinite recursive definition: `type {} = ...` referencing `Self` with #[decycle] trait bound",
-                            assoc_ident;
-                            help = assoc_ident.span() =>
-                            "associated type `{}` has a bound on a #[decycle] trait, \
-                             and this assignment references `Self`, creating a cycle in the ranking mechanism",
-                            assoc_ident
-                        );
+                    Type::Reference(TypeReference { mutability: Some(_), elem, .. })
+                        if matches!(&**elem, Type::Path(p) if p.path.is_ident("Self")) =>
+                    {
+                        if !wrapper.allows_mut() {
+                            abort!(
+                                receiver,
+                                "`{}` takes `&mut self`, but `{}` gives only shared access to its pointee",
+                                sig.ident,
+                                quote!(#wrapper).to_string().replace(' ', "")
+                            )
+                        }
+                        match wrapper {
+                            ForwardWrapper::Ref(_) => quote!(&mut **self),
+                            // Spanned to the wrapper, so one that isn't `DerefMut` is reported
+                            // on its name in `forward(..)`.
+                            ForwardWrapper::Path(path) => {
+                                let deref_mut = template_quote::quote_spanned! {
+                                    syn::spanned::Spanned::span(path) => ::core::ops::DerefMut::deref_mut
+                                };
+                                quote!(&mut *#deref_mut(self))
+                            }
+                        }
                     }
+                    _ => abort!(
+                        receiver,
+                        "only `&self` and `&mut self` methods can be forwarded through `{}`",
+                        quote!(#wrapper).to_string().replace(' ', "")
+                    ),
+                };
+                for (ix, input) in sig.inputs.iter_mut().enumerate() {
+                    input.reduce_pat(ix);
                 }
+                let margs = type_const_idents(&sig.generics);
+                let do_turbofish = !margs.is_empty() && !sig_has_impl_trait_input(&sig);
+                output.extend(quote! {
+                    #(for attr in cfg_attrs(attrs)) { #attr }
+                    #{track_caller_attr(&sig)}
+                    #[allow(deprecated)]
+                    #sig {
+                        #path::#{&sig.ident}
+                        #(if do_turbofish) { ::<#(#margs),*> }
+                        (
+                            #pointee
+                            #(for input in sig.inputs.iter().skip(1)) {
+                                , #{input.variable()}
+                            }
+                        )
+                    }
+                })
+            }
+            TraitItem::Type(TraitItemType {
+                attrs,
+                ident,
+                generics,
+                ..
+            }) => {
+                let targs = generics.ty_generics();
+                output.extend(quote! {
+                    #(for attr in cfg_attrs(attrs)) { #attr }
+                    type #ident #generics = #types_path::#ident #targs #{&generics.where_clause};
+                })
             }
+            TraitItem::Const(TraitItemConst {
+                attrs, ident, ty, ..
+            }) => output.extend(quote! {
+                #(for attr in cfg_attrs(attrs)) { #attr }
+                #[allow(deprecated)]
+                const #ident: #ty = #path::#ident;
+            }),
+            other => abort!(other, "unsupported item in a forwarded #[decycle] trait"),
         }
     }
+    output
 }
 
-/// A `#[decycle]` trait listed as a supertrait of ANOTHER `#[decycle]` trait — both in the
-/// same `replacing_table` batch — makes the ranked-trait definitions mutually referential
-/// in a way the rank-rewriting scheme can't discharge (E0283 at the use site: the
-/// supertrait bound resolves to the shadowed dummy trait, not the ranked one). Short-term:
-/// reject it outright rather than emit something that fails downstream with a confusing
-/// error.
-fn check_no_decycle_supertraits(
-    replacing_table: &HashMap<Ident, (ItemTrait, usize, Vec<ItemImpl>)>,
-) {
-    let decycle_idents: std::collections::HashSet<&Ident> = replacing_table.keys().collect();
-
-    for (trait_, _, _) in replacing_table.values() {
-        for bound in &trait_.supertraits {
-            let TypeParamBound::Trait(TraitBound { path, .. }) = bound else {
-                continue;
-            };
-            if path.segments.len() == 1 && decycle_idents.contains(&path.segments[0].ident) {
-                abort!(
-                    path,
-                    "a #[decycle] trait cannot be a supertrait of another #[decycle] trait"
+/// The forwarding impls `#[decycle(forward(...))]` asks for, emitted beside the Final impls.
+/// For each wrapper `W`, the ranked trait is implemented at every rank by forwarding to the
+/// pointee at the SAME rank (`impl<Rank, T: XRanked<Rank>> XRanked<Rank> for W<T>`, see
+/// [`ranked_trait_path`]), so a `W<T>: X` bound in a cycle body keeps counting down instead
+/// of re-entering through the Final impl. The types trait and the original trait are
+/// forwarded the same way.
+fn emit_forwarding_impls(
+    trait_: &ItemTrait,
+    traits: &[ItemTrait],
+    rank_loc: usize,
+    wrappers: &[ForwardWrapper],
+) -> TokenStream {
+    let fwd = name!("Fwd");
+    let fwd_ty: Type = parse_quote!(#fwd);
+    let lt = Lifetime::new("'__dcl_fwd", Span::call_site());
+    let rank = name!("Rank");
+    let args = trait_.generics.ty_generics();
+    let ranked_args = args.insert(rank_loc, parse_quote!(#rank));
+    let ranked_path = ranked_trait_path(&trait_.ident);
+    let assoc = assoc_type_idents(trait_);
+    let (sm, rm) = (shadowing_module_name(), ranked_traits_module_name());
+    let types_path: Path = parse_quote!(#sm::#rm::#{ranked_types_trait_name(&trait_.ident)});
+    let types_path_args = types_trait_path(&trait_.ident, &args);
+    let types_path_full: Path = parse_quote!(#sm::#rm::#types_path_args);
+    let ranked_generics = trait_.generics.insert(rank_loc, parse_quote!(#rank));
+    let cfgs = cfg_attrs(&trait_.attrs);
+    let mut output = TokenStream::new();
+    for wrapper in wrappers {
+        let wrapped = wrapper.wrap(&fwd_ty, &lt);
+        let header = |generics: &Generics, bound: TokenStream| {
+            let (lts, others): (Vec<_>, Vec<_>) = generics
+                .params
+                .iter()
+                .partition(|p| matches!(p, GenericParam::Lifetime(_)));
+            quote! {
+                <
+                    #(if matches!(wrapper, ForwardWrapper::Ref(_))) { #lt, }
+                    #(for p in lts) { #{generic_param_bounded(p)}, }
+                    #(for p in others) { #{generic_param_bounded(p)}, }
+                    #fwd: #(if wrapper.allows_unsized()) { ?Sized + } #bound
+                >
+            }
+        };
+        // Ranked: the method signatures are the ranked trait's own, with the types trait
+        // spelled from this (sibling) scope — minus its `Self: XTypes` bound, which would
+        // shadow the forwarding types impl below.
+        let ranked_items: Vec<TraitItem> = trait_
+            .items
+            .iter()
+            .filter(|item| assoc.is_empty() || !matches!(item, TraitItem::Type(_)))
+            .map(|item| {
+                let mut item = process_trait_item_for_ranked(
+                    item,
+                    trait_,
+                    traits,
+                    (!assoc.is_empty()).then_some((&assoc[..], &types_path_full)),
+                    &quote!(#sm::#rm::),
                 );
+                if let (TraitItem::Fn(f), false) = (&mut item, assoc.is_empty()) {
+                    let where_clause = f.sig.generics.where_clause.as_mut().unwrap();
+                    where_clause.predicates.pop();
+                    if where_clause.predicates.is_empty() {
+                        f.sig.generics.where_clause = None;
+                    }
+                }
+                item
+            })
+            .collect();
+        output.extend(quote! {
+            #(for attr in &cfgs) { #attr }
+            #[allow(unused_variables)]
+            impl #{header(&ranked_generics, quote!(#ranked_path #ranked_args))}
+            #ranked_path #ranked_args for #wrapped
+            #(if !assoc.is_empty()) {
+                where #fwd: #types_path_full
+            }
+            {
+                #{emit_forwarding_items(
+                    ranked_items.iter(),
+                    wrapper,
+                    &quote!(<#fwd as #ranked_path #ranked_args>),
+                    &quote!(<#fwd as #types_path_full>),
+                )}
             }
+        });
+        if !assoc.is_empty() {
+            output.extend(quote! {
+                #(for attr in &cfgs) { #attr }
+                impl #{header(&trait_.generics, quote!(#types_path_full))}
+                #types_path #args for #wrapped {
+                    #{emit_forwarding_items(
+                        trait_.items.iter().filter(|item| matches!(item, TraitItem::Type(_))),
+                        wrapper,
+                        &quote!(<#fwd as #types_path_full>),
+                        &quote!(<#fwd as #types_path_full>),
+                    )}
+                }
+            });
         }
+        // Original: forwards to the pointee's own (Final) impl.
+        output.extend(quote! {
+            #(for attr in &cfgs) { #attr }
+            #[allow(unused_variables)]
+            impl #{header(&trait_.generics, quote!(#{&trait_.ident} #args))}
+            #{&trait_.ident} #args for #wrapped #{&trait_.generics.where_clause}
+            {
+                #{emit_forwarding_items(
+                    trait_.items.iter(),
+                    wrapper,
+                    &quote!(<#fwd as #{&trait_.ident} #args>),
+                    &quote!(<#fwd as #{&trait_.ident} #args>),
+                )}
+            }
+        });
     }
+    output
 }
 
 pub fn finalize(args: FinalizeArgs) -> TokenStream {
@@ -2857,6 +5957,11 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
     // `replacing_table`'s idents (leaf/inductive impls, the re-entry engine, diagnostics)
     // sees the local name for free.
     let mut traits = args.traits.clone();
+    for item in traits.iter().flat_map(|t| &t.items) {
+        if let TraitItem::Fn(tf) = item {
+            erased_method_params(tf);
+        }
+    }
     for (original, local) in &args.renames {
         if let Some(t) = traits.iter_mut().find(|t| &t.ident == original) {
             t.ident = local.clone();
@@ -2875,9 +5980,15 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
         .iter()
         .flat_map(|ar| ar.normalize.iter().cloned())
         .collect();
-    let mut contents = args.contents.clone();
+    // Inherent impls are helpers (`emit_ranked_helpers`), not members of the cycle.
+    let (mut contents, helper_impls): (Vec<ItemImpl>, Vec<ItemImpl>) =
+        args.contents.iter().cloned().partition(|impl_| impl_.trait_.is_some());
     for impl_ in &mut contents {
         normalize_obligation_targets(impl_, &normalize_rules);
+        inherit_cyclic_defaults(impl_, &traits);
+        hoist_method_cyclic_bounds(impl_, &traits);
+        hoist_trait_cyclic_bounds(impl_, &traits);
+        syn::visit_mut::VisitMut::visit_item_impl_mut(&mut DesugarFnSugar { traits: &traits }, impl_);
     }
 
     let mut replacing_table: HashMap<Ident, (ItemTrait, usize, Vec<_>)> = traits
@@ -2911,8 +6022,6 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
         })
         .collect();
 
-    check_assoc_type_self(&replacing_table);
-    check_no_decycle_supertraits(&replacing_table);
 
     // C2: enroll each foreign-typed but in-module-impl'd concrete impl (e.g.
     // `impl __UnparseDyn for Group<Substruct,O,C>`) into its trait's ranked set. The
@@ -2925,6 +6034,10 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
         for impl_ in &ar.foreign_impls {
             let mut impl_ = impl_.clone();
             normalize_obligation_targets(&mut impl_, &normalize_rules);
+            inherit_cyclic_defaults(&mut impl_, &traits);
+            hoist_method_cyclic_bounds(&mut impl_, &traits);
+            hoist_trait_cyclic_bounds(&mut impl_, &traits);
+            syn::visit_mut::VisitMut::visit_item_impl_mut(&mut DesugarFnSugar { traits: &traits }, &mut impl_);
             let Some(seg) = impl_.trait_.as_ref().and_then(|t| t.1.segments.last()) else {
                 abort!(impl_, "decycle also_rank: a foreign impl must be a trait impl");
             };
@@ -2940,6 +6053,8 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
         }
     }
 
+    reject_overlapping_blanket_impls(&replacing_table, &traits);
+
     // F2: a renamed trait's ORIGINAL name (`#[decycle] use super::T as R;`) still leaks into
     // `shadowing_module`'s scope via `use super::super::*;` (reaching whatever contains the
     // `#[decycle] mod`, where the un-renamed `T` lives) even though the LOCAL alias `R` is
@@ -2979,6 +6094,10 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
         }
     });
 
+    // Every method `rank_method_generics` gives a hidden `MRank`, and every helper
+    // `emit_ranked_helpers` copies.
+    let router = RouteRankedCalls::new(&traits, &replacing_table, &helper_impls, &args.fns);
+
     let _output = TokenStream::new();
     let initial_rank = initial_rank(args.recurse_level);
 
@@ -2993,6 +6112,32 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
         })
         .collect();
 
+    // Maps each trait with associated types to them and its types trait, as seen from inside
+    // `ranked_traits` (bare) and from `shadowing_module` (`ranked_traits::`-prefixed) — see
+    // `TraitReplacer::types_table`.
+    let types_table_in_ranked: HashMap<Ident, (Vec<Ident>, Path)> = replacing_table
+        .iter()
+        .filter_map(|(ident, (trait_, _, _))| {
+            let assoc = assoc_type_idents(trait_);
+            (!assoc.is_empty())
+                .then(|| (ident.clone(), (assoc, ranked_types_trait_name(ident).into())))
+        })
+        .collect();
+    let types_table_in_shadowing: HashMap<Ident, (Vec<Ident>, Path)> = types_table_in_ranked
+        .iter()
+        .map(|(ident, (assoc, types_path))| {
+            let types_path: Path = parse_quote!(#{name!("ranked_traits")}::#types_path);
+            (ident.clone(), (assoc.clone(), types_path))
+        })
+        .collect();
+    // Rewrites only `<T as Trait>::Assoc` projections (the ranked table is empty), for items
+    // emitted inside `ranked_traits`.
+    let types_replacer = TraitReplacer {
+        table: HashMap::new(),
+        rank_type: parse_quote!(()),
+        types_table: types_table_in_ranked,
+    };
+
     quote! {
         // this module is to prevent confliction of trait method call between ranked and non-ranked
         // traits
@@ -3007,6 +6152,15 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                 #[allow(unused)]
                 use super::super::*;
 
+                // The original traits, for `dyn Trait` (`object_trait_name`).
+                pub(super) mod #{trait_objects_module_name()} {
+                    #(for (trait_ident, (trait_, _, _)) in &replacing_table) {
+                        #(for attr in cfg_attrs(&trait_.attrs)) { #attr }
+                        #[allow(unused)]
+                        pub(in super::super) use super::super::super::#trait_ident as #{object_trait_name(trait_ident)};
+                    }
+                }
+
                 #(for (trait_, rank_loc, impls) in replacing_table.values()) {
 
                     // pub trait MyTraitRanked<'a, Rank, T>
@@ -3019,15 +6173,57 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                     // instantiating a path, e.g. `Xxx<N>`/`Xxx<7>`), so it can't be used here;
                     // `generic_param_bounded` (already used for re-entry fn generics below) keeps
                     // each param's bounds/kind and only strips defaults.
-                    #(let ranked_generics = trait_.generics.insert(*rank_loc, parse_quote!(#{name!("Rank")}))) {
+                    //
+                    // A trait with associated types declares them once, on a rank-free
+                    // `pub trait MyTraitTypes<'a, T>`, instead of on every rank: a
+                    // `<L as MyTrait>::Assoc` projection inside a cycle body then names the
+                    // same type whatever rank `L` is at (`TraitReplacer::types_table`).
+                    #(let ranked_generics = strip_trait_cyclic_bounds(&trait_.generics, &traits).insert(*rank_loc, parse_quote!(#{name!("Rank")}))) {
+                    #(let assoc = assoc_type_idents(trait_)) {
+                    #(let has_types = !assoc.is_empty()) {
+                    #(let types_ident = ranked_types_trait_name(&trait_.ident)) {
+                    #(let types_path = types_trait_path(&trait_.ident, &trait_.generics.ty_generics())) {
+                    #(for attr in cfg_attrs(&trait_.attrs)) { #attr }
                     #[allow(unused)]
                     #[doc(hidden)]
                     pub trait #{name!("{}Ranked", &trait_.ident)}
                     #(if !ranked_generics.params.is_empty()) {
                         < #(for p in &ranked_generics.params), { #{generic_param_bounded(p)} } >
                     }
-                    #{trait_.colon_token} #{&trait_.supertraits} {
-                        #(for item in &trait_.items) { #{process_trait_item_for_ranked(item)} }
+                    #{trait_.colon_token} #{ranked_supertraits(trait_, &replacing_table)} {
+                        #(for item in trait_.items.iter().filter(|item| !has_types || !matches!(item, TraitItem::Type(_)))) {
+                            #{process_trait_item_for_ranked(item, trait_, &traits, has_types.then_some((&assoc[..], &types_path)), &TokenStream::new())}
+                        }
+                        #(if trait_options(trait_).dyn_entries) {
+                            #(for item in dyn_entry_defaults(trait_)) { #item }
+                        }
+                    }
+
+                    #{emit_dyn_object_impl(trait_, *rank_loc, &traits, &decycle_path)}
+
+                    // The associated types keep their other bounds verbatim, but not their
+                    // #[decycle] ones (`type Assoc: MyTrait;`): each impl carries those as a
+                    // ranked edge to the type it assigns (`assoc_type_edges`), and the original
+                    // trait's Final impl discharges them at the initial rank — which is what
+                    // lets `type Assoc = Self;` / `= Box<Self>;` type-check, where the same
+                    // bound on every ranked impl used to recurse without end.
+                    #(if has_types) {
+                        #(for attr in cfg_attrs(&trait_.attrs)) { #attr }
+                        #[allow(unused)]
+                        #[doc(hidden)]
+                        pub trait #types_ident
+                        #(if !trait_.generics.params.is_empty()) {
+                            < #(for p in &trait_.generics.params), { #{generic_param_bounded(p)} } >
+                        }
+                        {
+                            #(for item in trait_.items.iter().filter(|item| matches!(item, TraitItem::Type(_)))) {
+                                #{without_cyclic_assoc_bounds(item, &traits)}
+                            }
+                        }
+                    }
+                    }
+                    }
+                    }
                     }
                     }
 
@@ -3039,14 +6235,69 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
 
                     #(for impl_ in impls) {
 
-                        #(let g = remove_cyclic_bounds(&impl_.generics, &replacing_table)) {
+                        // `remove_cyclic_bounds`, plus the `L: MyTraitTypes` bounds the
+                        // leaf's (inherited) associated types may need.
+                        #(let g = types_impl_generics(&impl_.generics, &replacing_table)) {
                             // Leaf: impl<'a, T> MyTraitRanked<'a, (), T> for ImplSelfTy
+                            #(for attr in cfg_attrs(&impl_.attrs)) { #attr }
                             #[allow(unused_variables)]
-                            impl #{impl_.generics.impl_generics()}
+                            impl #{g.impl_generics()}
                             #{name!("{}Ranked", &trait_.ident)}
                             #{impl_.trait_.as_ref().unwrap().1.ty_generics().insert(*rank_loc, parse_quote![()])}
-                            for #{&impl_.self_ty} #{&g.where_clause} {
-                                #{emit_impl_items_leaf(impl_, trait_, args.support_infinite_cycle, &decycle_path)}
+                            for #{&impl_.self_ty} #{
+                                let mut g = g.clone();
+                                let mut super_bounds = decycle_supertrait_predicates(
+                                    trait_, impl_, &replacing_table, &floor_rank(), &TokenStream::new(),
+                                );
+                                super_bounds.extend(blanket_floor_predicates(impl_, &replacing_table));
+                                if !super_bounds.is_empty() {
+                                    g.make_where_clause().predicates.extend(super_bounds);
+                                }
+                                g.where_clause
+                            } {
+                                #{
+                                    let mut leaf_impl = impl_.clone();
+                                    let assoc = assoc_type_idents(trait_);
+                                    let types_path = types_trait_path(
+                                        &trait_.ident,
+                                        &impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments,
+                                    );
+                                    syn::visit_mut::VisitMut::visit_item_impl_mut(
+                                        &mut QualifySelfAssoc { assoc: &assoc, types_path: &types_path },
+                                        &mut leaf_impl,
+                                    );
+                                    syn::visit_mut::VisitMut::visit_item_impl_mut(
+                                        &mut types_replacer.clone(),
+                                        &mut leaf_impl,
+                                    );
+                                    for item in &mut leaf_impl.items {
+                                        if let ImplItem::Fn(f) = item {
+                                            rank_method_generics(&mut f.sig, &traits, &TokenStream::new());
+                                        }
+                                    }
+                                    emit_impl_items_leaf(&leaf_impl, trait_, &traits, args.support_infinite_cycle, &decycle_path)
+                                }
+                            }
+
+                            // Types: impl<'a, T> MyTraitTypes<'a, T> for ImplSelfTy, holding
+                            // the user's associated type definitions once for every rank.
+                            #(if !assoc_type_idents(trait_).is_empty()) {
+                                #(for attr in cfg_attrs(&impl_.attrs)) { #attr }
+                                impl #{g.impl_generics()}
+                                #{ranked_types_trait_name(&trait_.ident)}
+                                #{&impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments}
+                                for #{&impl_.self_ty} #{&g.where_clause} {
+                                    #(for item in impl_.items.iter().filter(|item| matches!(item, ImplItem::Type(_)))) {
+                                        #{
+                                            let mut item = item.clone();
+                                            syn::visit_mut::VisitMut::visit_impl_item_mut(
+                                                &mut types_replacer.clone(),
+                                                &mut item,
+                                            );
+                                            item
+                                        }
+                                    }
+                                }
                             }
                         }
 
@@ -3061,9 +6312,10 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
             // This prevents method calls in the inductive step body from resolving
             // to the Final impls (which would reset rank to InitialRank).
             // Local definitions shadow glob imports in Rust.
-            #(for trait_ in replacing_table.keys()) {
+            #(for (trait_ident, (trait_, _, _)) in &replacing_table) {
+                #(for attr in cfg_attrs(&trait_.attrs)) { #attr }
                 #[allow(non_camel_case_types)]
-                trait #trait_ {}
+                trait #trait_ident {}
             }
 
             // F2: also shadow a renamed trait's ORIGINAL name (see `renamed_original_dummies`
@@ -3079,6 +6331,17 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
             #[allow(unused)]
             use #{name!("ranked_traits")}::*;
 
+            #{emit_ranked_helpers(
+                &helper_impls,
+                &args.fns,
+                &traits,
+                &replacing_table,
+                &trait_replacer_table,
+                &types_table_in_shadowing,
+                &router,
+                &decycle_path,
+            )}
+
             #(for (trait_, rank_loc, impls) in replacing_table.values()) {
                 #(for (impl_ix, impl_) in impls.iter().enumerate()) {
 
@@ -3092,6 +6355,22 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                         use syn::visit_mut::VisitMut;
 
                         let mut modified_impl = impl_.clone();
+                        modified_impl.attrs.retain(|attr| !is_erase_marker(attr));
+
+                        // A method's own cyclic bounds (`fn visit<V: Ca>`) are ranked at its
+                        // caller-inferred `MRank`, not stepped down with the body.
+                        for item in &mut modified_impl.items {
+                            if let ImplItem::Fn(f) = item {
+                                f.attrs = method_attrs(&f.attrs, AttrSite::Body).into_iter().cloned().collect();
+                                rank_method_generics(&mut f.sig, &traits, &quote!(#{name!("ranked_traits")}::));
+                            }
+                        }
+                        router.clone().visit_item_impl_mut(&mut modified_impl);
+                        let mut dyn_router = RouteDynCalls::new(&traits, &name!("Rank"), &decycle_path);
+                        dyn_router.visit_item_impl_mut(&mut modified_impl);
+                        if let Some(pred) = dyn_router.rank_depth_predicate(&decycle_path) {
+                            modified_impl.generics.make_where_clause().predicates.push(pred);
+                        }
 
                         // Desugar `impl Trait` in method signatures to match the ranked
                         // trait definition (which also desugars via process_trait_item_for_ranked).
@@ -3107,14 +6386,62 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                         TraitReplacer {
                             table: trait_replacer_table.clone(),
                             rank_type: parse_quote!((#{name!("Rank")},)),
+                            types_table: HashMap::new(),
                         }.visit_path_mut(&mut modified_impl.trait_.as_mut().unwrap().1);
 
+                        // The ranked trait doesn't declare the associated types: qualify
+                        // `Self::Assoc` through the types trait, and require `L: MyTraitTypes`
+                        // wherever a `L: MyTrait` bound's projections may be named (a ranked
+                        // bound no longer implies it — see `process_trait_item_for_ranked`).
+                        let assoc = assoc_type_idents(trait_);
+                        if !assoc.is_empty() {
+                            let mut types_path = types_trait_path(
+                                &trait_.ident,
+                                &impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments,
+                            );
+                            types_path.segments.insert(0, name!("ranked_traits").into());
+                            QualifySelfAssoc { assoc: &assoc, types_path: &types_path }
+                                .visit_item_impl_mut(&mut modified_impl);
+                        }
+                        let mut types_bounds = types_where_predicates(
+                            &impl_.generics,
+                            &replacing_table,
+                            &quote!(#{name!("ranked_traits")}::),
+                        );
+                        types_bounds.extend(constrained_types_predicates(
+                            &impl_.generics,
+                            &replacing_table,
+                            &quote!(#{name!("ranked_traits")}::),
+                        ));
+                        if !types_bounds.is_empty() {
+                            modified_impl.generics.make_where_clause().predicates.extend(types_bounds);
+                        }
+                        // `TypedRanked<(Rank,)>: EvaluateRanked<(Rank,)>` — see
+                        // `inherited_supertrait_predicates`.
+                        let super_bounds = inherited_supertrait_predicates(
+                            trait_,
+                            impl_,
+                            &replacing_table,
+                            &quote!(#{name!("ranked_traits")}::),
+                        );
+                        if !super_bounds.is_empty() {
+                            modified_impl.generics.make_where_clause().predicates.extend(super_bounds);
+                        }
+
                         // Step 2: Rewrite all trait paths in body + where clause with rank=Rank
                         TraitReplacer {
                             table: trait_replacer_table.clone(),
                             rank_type: parse_quote!(#{name!("Rank")}),
+                            types_table: types_table_in_shadowing.clone(),
                         }.visit_item_impl_mut(&mut modified_impl);
 
+                        // The associated types live on the trait's types impl (emitted next to
+                        // the leaf); the ranked trait doesn't declare them, and its methods
+                        // reach them through their own `Self: XTypes` bound.
+                        if !assoc_type_idents(trait_).is_empty() {
+                            modified_impl.items.retain(|item| !matches!(item, ImplItem::Type(_)));
+                        }
+
                         // Add Rank as a generic parameter
                         modified_impl.generics.params.push(parse_quote!(#{name!("Rank")}));
                         if modified_impl.generics.lt_token.is_none() {
@@ -3158,8 +6485,23 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                         let mut register_once_item = TokenStream::new();
                         if args.support_infinite_cycle {
                             let rule1_ok = rule1_registration_ok(trait_, impl_, &replacing_table);
-                            let (shared_regs, binder_lts) =
-                                build_shared_registrations(impl_, &replacing_table, &decycle_path);
+                            // A `#[decycle]` supertrait is a cross-edge too (`self.base()` in
+                            // a `Derived` body descends `Base`'s chain from this rank), so
+                            // register it like a cyclic `SelfTy: Base` bound.
+                            let mut registering_impl = impl_.clone();
+                            let super_bounds: Vec<WherePredicate> =
+                                decycle_supertrait_paths(trait_, impl_, &replacing_table)
+                                    .into_iter()
+                                    .map(|path| parse_quote!(#{&impl_.self_ty}: #path))
+                                    .collect();
+                            if !super_bounds.is_empty() {
+                                registering_impl.generics.make_where_clause().predicates.extend(super_bounds);
+                            }
+                            let (shared_regs, binder_lts) = build_shared_registrations(
+                                &registering_impl,
+                                &replacing_table,
+                                &decycle_path,
+                            );
                             let register_once_fn =
                                 name!("__dcl_register_once_{}_{}", &trait_.ident, impl_ix);
                             // A preserved non-cyclic bound may still mention bare `Self`
@@ -3186,6 +6528,7 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                                 stripped.gt_token = Some(Default::default());
                             }
                             register_once_item = quote! {
+                                #(for attr in cfg_attrs(&impl_.attrs)) { #attr }
                                 #[doc(hidden)]
                                 #[allow(non_snake_case, unused, dead_code)]
                                 fn #register_once_fn #{stripped.impl_generics()} ()
@@ -3209,11 +6552,16 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                                     // statements, so flattening changes nothing observable
                                     // (same order, same scope end).
                                     let old_stmts = block.stmts.clone();
+                                    // `Re::<Self, V>` needs the original `V: Ca`, which only
+                                    // the Final impl's entry proves (it registers it there).
                                     let rule1_regs = build_rule1_registrations(
                                         trait_,
                                         impl_,
                                         sig,
-                                        rule1_ok,
+                                        rule1_ok
+                                            && !impl_.items.iter().any(|it| matches!(it,
+                                                ImplItem::Fn(f) if f.sig.ident == sig.ident
+                                                    && has_method_generic_cyclic_bound(&f.sig, &traits))),
                                         &decycle_path,
                                     );
                                     *block = parse_quote! {
@@ -3229,10 +6577,15 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                             }
                         }
 
-                        quote!(
-                            #register_once_item
-                            #[allow(unused_variables, unused_unsafe)]
-                            #modified_impl
+                        // Emitted in `shadowing_module`, where a bare `dyn Trait` would name
+                        // the dummy.
+                        qualify_trait_object_tokens(
+                            quote!(
+                                #register_once_item
+                                #[allow(unused_variables, unused_unsafe)]
+                                #modified_impl
+                            ),
+                            &trait_replacer_table,
                         )
                     }
                 }
@@ -3257,28 +6610,93 @@ pub fn finalize(args: FinalizeArgs) -> TokenStream {
                     #(let bareparam = (args.support_infinite_cycle
                         && impl_has_bare_param_cyclic_bound(impl_, &replacing_table))
                         .then_some((trait_, &decycle_path))) {
-                    #(for attr in &impl_.attrs) { #attr }
+                    #(let (dyn_bounds, dyn_entries) = emit_dyn_entries(
+                        trait_,
+                        impl_,
+                        *rank_loc,
+                        args.recurse_level,
+                        args.support_infinite_cycle,
+                    )) {
+                    #(let delegated = {
+                        let mut delegated = impl_.clone();
+                        delegated.items.retain(|item| {
+                            !matches!(item, ImplItem::Fn(f) if has_method_generic_cyclic_bound(&f.sig, &traits))
+                        });
+                        delegated
+                    }) {
+                    // An `expect` is for the user's code, which only the inductive copy (and
+                    // a method entry) compiles: here it would go unfulfilled.
+                    #(for attr in impl_.attrs.iter().filter(|attr| !attr.path().is_ident("expect") && !is_erase_marker(attr))) { #attr }
                     #{&impl_.defaultness} #{&impl_.unsafety} impl #{g.impl_generics()}
                     #{&trait_.ident}
                     #{&impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments}
-                    for #{&impl_.self_ty} #{g.push_predicate(parse_quote!(
-                        Self: #{name!("shadowing_module")}::#{name!("ranked_traits")}::#{name!("{}Ranked", &trait_.ident)}
-                        #{impl_.trait_.as_ref().unwrap().1.ty_generics().insert(*rank_loc, initial_rank.clone())}
-                    )).where_clause}
+                    for #{&impl_.self_ty} #{
+                        let mut g = g.push_predicate(parse_quote!(
+                            Self: #{name!("shadowing_module")}::#{name!("ranked_traits")}::#{name!("{}Ranked", &trait_.ident)}
+                            #{impl_.trait_.as_ref().unwrap().1.ty_generics().insert(*rank_loc, initial_rank.clone())}
+                        ));
+                        // `<Self as …Types>::Assoc` (below) needs the types impl's own bounds.
+                        let mut types_bounds = types_where_predicates(
+                            &impl_.generics,
+                            &replacing_table,
+                            &quote!(#{name!("shadowing_module")}::#{name!("ranked_traits")}::),
+                        );
+                        // The constraints on concrete siblings the Final header stripped.
+                        types_bounds.extend(constrained_types_predicates(
+                            &impl_.generics,
+                            &replacing_table,
+                            &quote!(#{name!("shadowing_module")}::#{name!("ranked_traits")}::),
+                        ));
+                        types_bounds.extend(dyn_bounds.iter().cloned());
+                        if !types_bounds.is_empty() {
+                            g.make_where_clause().predicates.extend(types_bounds);
+                        }
+                        g.where_clause
+                    }
                     {
-                        #{emit_impl_items_delegate(
+                        #dyn_entries
+                        #{emit_method_entries(
+                            trait_,
                             impl_,
+                            &traits,
+                            &replacing_table,
+                            args.support_infinite_cycle.then_some(&decycle_path),
+                        )}
+                        #{emit_impl_items_delegate(
+                            &delegated,
                             quote!(
                                 <Self as #{name!("shadowing_module")}::#{name!("ranked_traits")}::#{name!("{}Ranked", &trait_.ident)}
                                 #{impl_.trait_.as_ref().unwrap().1.ty_generics().insert(*rank_loc, initial_rank.clone())} >
                             ),
+                            if assoc_type_idents(trait_).is_empty() {
+                                quote!(
+                                    <Self as #{name!("shadowing_module")}::#{name!("ranked_traits")}::#{name!("{}Ranked", &trait_.ident)}
+                                    #{impl_.trait_.as_ref().unwrap().1.ty_generics().insert(*rank_loc, initial_rank.clone())} >
+                                )
+                            } else {
+                                quote!(
+                                    <Self as #{name!("shadowing_module")}::#{name!("ranked_traits")}::#{ranked_types_trait_name(&trait_.ident)}
+                                    #{&impl_.trait_.as_ref().unwrap().1.segments.last().unwrap().arguments} >
+                                )
+                            },
                             bareparam,
                         )}
                     }
                     }
+                    }
+                    }
                 }
             }
         }
+
+        // `#[decycle(forward(...))]`: rank-preserving impls for the listed wrappers.
+        #(for (trait_, rank_loc, _) in replacing_table.values()) {
+            #{emit_forwarding_impls(trait_, &traits, *rank_loc, &forward_wrappers(trait_))}
+        }
+
+        // The helpers as written, for callers outside the cycle.
+        #(for impl_ in &helper_impls) { #impl_ }
+        #(for item_fn in &args.fns) { #item_fn }
     }
 }
 
@@ -3323,6 +6741,7 @@ mod tests {
             support_infinite_cycle,
             renames: Vec::new(),
             also_rank: Vec::new(),
+            fns: Vec::new(),
             decycle_path: Some(parse_quote!(::decycle)),
         };
         (args, ca_trait, cb_trait)
@@ -3481,6 +6900,7 @@ mod tests {
             support_infinite_cycle: false,
             renames: Vec::new(),
             also_rank: Vec::new(),
+            fns: Vec::new(),
             // D1: the programmatic override. Deliberately spelled WITHOUT the substring
             // "decycle" so this test can assert on `get_crate_identity()` ("decycle")
             // without the caller-supplied path itself tripping the assertion.
@@ -3563,6 +6983,7 @@ mod tests {
             support_infinite_cycle: false,
             renames: Vec::new(),
             also_rank: Vec::new(),
+            fns: Vec::new(),
             decycle_path: Some(parse_quote!(::decycle)),
         };
         let out = finalize(args).to_string();
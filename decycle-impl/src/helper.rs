@@ -1,5 +1,4 @@
 use proc_macro2::{Span, TokenStream};
-use proc_macro_error::*;
 use syn::punctuated::Punctuated;
 use syn::*;
 use template_quote::quote;
@@ -38,22 +37,50 @@ pub fn path_insert_type_arg(path: &mut Path, index: usize, ty: Type) {
         PathArguments::AngleBracketed(ref mut angle_args) => {
             angle_args.args.insert(index, arg);
         }
-        // A #[decycle] trait referenced with `Fn(...)`-sugar (`where B: Cb(usize) -> usize`)
-        // reaches here through `TraitReplacer` (the where-clause/body rewriter): it steals
-        // the ORIGINAL `Parenthesized` arguments onto the ranked-trait replacement path
-        // before this call is meant to insert the Rank argument. Silently doing nothing
-        // (the old behavior) left the Rank argument out entirely, producing a ranked-trait
-        // reference desugared as `CbRanked<(usize,), Output = usize>` — missing its Rank
-        // parameter — which cascades into confusing, seemingly unrelated errors downstream
-        // (E0658/E0220/E0277) instead of naming the actual problem. Abort here instead,
-        // matching `PathArgumentsScheme::insert`'s identical rejection for an impl's own
-        // (syntactically distinct, but equally unsupported) parenthesized trait reference.
+        // `Cb(usize) -> usize` names `Cb<(usize,), Output = usize>`: insert into that.
         PathArguments::Parenthesized(pa) => {
-            abort!(pa, "unsupported parenthesized generic arguments on a #[decycle] trait")
+            let mut angle_args = fn_sugar_to_angle_bracketed(pa);
+            angle_args.args.insert(index, arg);
+            last_seg.arguments = PathArguments::AngleBracketed(angle_args);
         }
     }
 }
 
+/// Desugars `Fn(...)`-style arguments the way rustc does: `Cb(A, B) -> R` is
+/// `Cb<(A, B), Output = R>`, and a missing return type is `Output = ()`.
+pub fn fn_sugar_to_angle_bracketed(
+    pa: &ParenthesizedGenericArguments,
+) -> AngleBracketedGenericArguments {
+    let mut elems = pa.inputs.clone();
+    if elems.len() == 1 && !elems.trailing_punct() {
+        elems.push_punct(Default::default());
+    }
+    let inputs = Type::Tuple(TypeTuple {
+        paren_token: pa.paren_token,
+        elems,
+    });
+    let output = match &pa.output {
+        ReturnType::Type(_, ty) => (**ty).clone(),
+        ReturnType::Default => parse_quote!(()),
+    };
+    AngleBracketedGenericArguments {
+        colon2_token: None,
+        lt_token: Default::default(),
+        args: [
+            GenericArgument::Type(inputs),
+            GenericArgument::AssocType(AssocType {
+                ident: Ident::new("Output", pa.paren_token.span.join()),
+                generics: None,
+                eq_token: Default::default(),
+                ty: output,
+            }),
+        ]
+        .into_iter()
+        .collect(),
+        gt_token: Default::default(),
+    }
+}
+
 pub trait FnArgScheme {
     fn reduce_pat(&mut self, ix: usize);
     fn variable(&self) -> TokenStream;
@@ -61,6 +88,14 @@ pub trait FnArgScheme {
 
 impl FnArgScheme for FnArg {
     fn reduce_pat(&mut self, ix: usize) {
+        // `mut self: Box<Self>`: a forwarding body only moves `self` on, which `unused_mut`
+        // would flag at the user's span. (`&mut self` keeps its `mut`: that one is the
+        // reference's.)
+        if let FnArg::Receiver(receiver) = self {
+            if receiver.reference.is_none() {
+                receiver.mutability = None;
+            }
+        }
         if let FnArg::Typed(PatType { pat, .. }) = self {
             match pat.as_mut() {
                 // Keep the ident (and its `mut`, which is signature-only and doesn't
@@ -123,11 +158,10 @@ impl PathArgumentsScheme for PathArguments {
                 angle_args.args.insert(index, GenericArgument::Type(ty));
                 PathArguments::AngleBracketed(angle_args)
             }
-            // Only reachable when a #[decycle] trait is named with `Fn(...)`-sugar syntax
-            // (`impl FnLike(A) -> B for X`), which isn't a supported form of a decycle
-            // trait bound — a clean compile error instead of an internal panic.
             PathArguments::Parenthesized(pa) => {
-                abort!(pa, "unsupported parenthesized generic arguments on a #[decycle] trait")
+                let mut angle_args = fn_sugar_to_angle_bracketed(pa);
+                angle_args.args.insert(index, GenericArgument::Type(ty));
+                PathArguments::AngleBracketed(angle_args)
             }
         }
     }
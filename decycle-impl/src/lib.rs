@@ -17,7 +17,7 @@ pub use proc_macro_error;
 #[cfg(feature = "type-leak")]
 pub use type_leak;
 
-pub use process_module::process_module;
+pub use process_module::{process_module, resume_expand_first, ExpandFirstArgs};
 #[cfg(feature = "type-leak")]
 pub use process_trait::process_trait;
 
@@ -115,13 +115,44 @@ pub(crate) fn randomize_impl_generics(
 /// form is matched against that name. (Dropping the manifest read removes the `toml` dependency and
 /// lowers the crate's MSRV.)
 fn is_decycle_attribute(attr: &Attribute, decycle_crate: &Ident) -> bool {
-    let path = attr.path();
+    is_decycle_path(attr.path(), decycle_crate)
+}
+
+/// [`is_decycle_attribute`] on an attribute's path alone (a meta inside `cfg_attr(..)`).
+fn is_decycle_path(path: &Path, decycle_crate: &Ident) -> bool {
     path.is_ident("decycle")
         || (path.segments.len() == 2
             && (path.segments[0].ident == "decycle" || &path.segments[0].ident == decycle_crate)
             && path.segments[1].ident == "decycle")
 }
 
+/// Removes the `#[decycle(..)]` markers from a `#[decycle]` trait's methods, for the trait as
+/// emitted.
+fn strip_method_markers(trait_: &mut ItemTrait, decycle_crate: &Ident) {
+    for item in &mut trait_.items {
+        if let TraitItem::Fn(f) = item {
+            f.attrs.retain(|attr| !is_decycle_attribute(attr, decycle_crate));
+        }
+    }
+}
+
+/// Spells the `#[decycle(..)]` markers on a `#[decycle]` trait's methods `#[decycle(..)]`, as
+/// `finalize` looks for them on the copy it's handed.
+fn carry_method_markers(trait_: &mut ItemTrait, decycle_crate: &Ident) {
+    for item in &mut trait_.items {
+        if let TraitItem::Fn(f) = item {
+            for attr in &mut f.attrs {
+                if is_decycle_attribute(attr, decycle_crate) {
+                    if let Meta::List(list) = &attr.meta {
+                        let tokens = &list.tokens;
+                        *attr = parse_quote!(#[decycle(#tokens)]);
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn ident_to_path(ident: &Ident) -> Path {
     Path {
         leading_colon: None,
@@ -1,8 +1,7 @@
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use proc_macro_error::*;
-use std::collections::HashSet;
-use syn::spanned::Spanned;
+use std::collections::{HashMap, HashSet};
 use syn::*;
 use template_quote::quote;
 
@@ -56,6 +55,181 @@ fn check_submodule(module: &ItemMod, decycle_crate: &Ident) {
     Visitor { decycle_crate }.visit_item_mod(module);
 }
 
+/// Splits `#[cfg_attr(pred, a, b, ..)]` into `pred` and its attributes.
+fn parse_cfg_attr(attr: &Attribute) -> Option<(Meta, Vec<Meta>)> {
+    if !attr.path().is_ident("cfg_attr") {
+        return None;
+    }
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let pred: Meta = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let metas = punctuated::Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        Ok((pred, metas.into_iter().collect()))
+    })
+    .ok()
+}
+
+/// The attribute lists a decycle marker may sit in: an item's, and its trait or impl members'.
+fn marker_attr_lists(item: &mut Item) -> Vec<&mut Vec<Attribute>> {
+    match item {
+        Item::Trait(item_trait) => core::iter::once(&mut item_trait.attrs)
+            .chain(item_trait.items.iter_mut().filter_map(|item| match item {
+                TraitItem::Fn(f) => Some(&mut f.attrs),
+                TraitItem::Const(c) => Some(&mut c.attrs),
+                TraitItem::Type(t) => Some(&mut t.attrs),
+                _ => None,
+            }))
+            .collect(),
+        Item::Impl(item_impl) => core::iter::once(&mut item_impl.attrs)
+            .chain(item_impl.items.iter_mut().filter_map(|item| match item {
+                ImplItem::Fn(f) => Some(&mut f.attrs),
+                ImplItem::Const(c) => Some(&mut c.attrs),
+                ImplItem::Type(t) => Some(&mut t.attrs),
+                _ => None,
+            }))
+            .collect(),
+        Item::TraitAlias(ItemTraitAlias { attrs, .. }) | Item::Use(ItemUse { attrs, .. }) => {
+            vec![attrs]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The predicates of every `#[cfg_attr(pred, .., decycle, ..)]` in the module. rustc hands the
+/// module over with `cfg_attr` unexpanded, so `process_module` can't see such a marker.
+fn cfg_attr_marker_predicates(contents: &mut [Item], decycle_crate: &Ident) -> Vec<Meta> {
+    contents
+        .iter_mut()
+        .flat_map(marker_attr_lists)
+        .flat_map(|attrs| attrs.iter())
+        .filter_map(parse_cfg_attr)
+        .filter(|(_, metas)| {
+            metas
+                .iter()
+                .any(|meta| crate::is_decycle_path(meta.path(), decycle_crate))
+        })
+        .map(|(pred, _)| pred)
+        .collect()
+}
+
+/// The arguments of a `cfg` combinator (`all(..)`, `any(..)`, `not(..)`), or `None` for an
+/// option (`feature = "x"`, `unix`).
+fn cfg_combinator(pred: &Meta) -> Option<(String, Vec<Meta>)> {
+    let Meta::List(list) = pred else {
+        return None;
+    };
+    let name = list.path.get_ident()?.to_string();
+    if !matches!(name.as_str(), "all" | "any" | "not") {
+        return None;
+    }
+    let args = list
+        .parse_args_with(punctuated::Punctuated::<Meta, Token![,]>::parse_terminated)
+        .ok()?;
+    Some((name, args.into_iter().collect()))
+}
+
+/// The options a `cfg` predicate is built from, in order of appearance.
+fn cfg_options(pred: &Meta, out: &mut Vec<Meta>) {
+    match cfg_combinator(pred) {
+        Some((_, args)) => args.iter().for_each(|arg| cfg_options(arg, out)),
+        None => out.push(pred.clone()),
+    }
+}
+
+/// The value of a `cfg` predicate, given those of some options: `Some` if they settle it
+/// (`all()` holds, `any()` doesn't, whatever the options are).
+fn cfg_value(pred: &Meta, options: &[(Meta, bool)]) -> Option<bool> {
+    let Some((name, args)) = cfg_combinator(pred) else {
+        return options
+            .iter()
+            .find(|(option, _)| quote!(#pred).to_string() == quote!(#option).to_string())
+            .map(|(_, holds)| *holds);
+    };
+    let values: Vec<_> = args.iter().map(|arg| cfg_value(arg, options)).collect();
+    match name.as_str() {
+        "not" => values.first().copied().flatten().map(|value| !value),
+        "all" if values.contains(&Some(false)) => Some(false),
+        "any" if values.contains(&Some(true)) => Some(true),
+        "all" | "any" => values.iter().all(Option::is_some).then_some(name == "all"),
+        _ => unreachable!(),
+    }
+}
+
+/// How many `cfg` options the `cfg_attr` markers of one module may depend on. Each doubles the
+/// copies the module is expanded into.
+const MAX_CFG_ATTR_OPTIONS: usize = 3;
+
+/// The options the module's `cfg_attr` markers depend on, in order of appearance. Each copy the
+/// module is expanded into fixes all of them; past `MAX_CFG_ATTR_OPTIONS` that's rejected.
+fn cfg_attr_marker_options(predicates: &[Meta]) -> Vec<Meta> {
+    let mut found: Vec<Meta> = Vec::new();
+    for pred in predicates {
+        if cfg_value(pred, &[]).is_some() {
+            continue;
+        }
+        let mut options = Vec::new();
+        cfg_options(pred, &mut options);
+        for option in options {
+            if found
+                .iter()
+                .any(|seen| quote!(#seen).to_string() == quote!(#option).to_string())
+            {
+                continue;
+            }
+            if found.len() == MAX_CFG_ATTR_OPTIONS {
+                abort!(
+                    option,
+                    "decycle: `cfg_attr` markers in one module can depend on {} `cfg` options at \
+                     most, and `{}` is one more besides {}",
+                    MAX_CFG_ATTR_OPTIONS,
+                    quote!(#option).to_string().replace(' ', ""),
+                    found
+                        .iter()
+                        .map(|seen| format!("`{}`", quote!(#seen).to_string().replace(' ', "")))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    note = "the module is expanded once for each combination of their values";
+                    hint = "gate the whole module, or split it so each part depends on fewer options"
+                )
+            }
+            found.push(option);
+        }
+    }
+    found
+}
+
+/// Expands the decycle markers of every `#[cfg_attr(pred, ..)]` by `pred`'s value, given those of
+/// `options` (see `cfg_attr_marker_options`), keeping the other attributes under the `cfg_attr`.
+/// Markers whose predicate `options` don't settle are dropped.
+fn resolve_cfg_attr_marker(
+    attrs: &mut Vec<Attribute>,
+    options: &[(Meta, bool)],
+    decycle_crate: &Ident,
+) {
+    *attrs = std::mem::take(attrs)
+        .into_iter()
+        .flat_map(|attr| {
+            let Some((pred, metas)) = parse_cfg_attr(&attr) else {
+                return vec![attr];
+            };
+            let (markers, others): (Vec<_>, Vec<_>) = metas
+                .into_iter()
+                .partition(|meta| crate::is_decycle_path(meta.path(), decycle_crate));
+            if markers.is_empty() {
+                return vec![attr];
+            }
+            let mut out: Vec<Attribute> = Vec::new();
+            if cfg_value(&pred, options) == Some(true) {
+                out.extend(markers.iter().map(|meta| parse_quote!(#[#meta])));
+            }
+            if !others.is_empty() {
+                out.push(parse_quote!(#[cfg_attr(#pred, #(#others),*)]));
+            }
+            out
+        })
+        .collect();
+}
+
 fn process_trait_path(item: &Item) -> Vec<Path> {
     match item {
         Item::Trait(ItemTrait { ident, .. }) => {
@@ -118,112 +292,843 @@ fn collect_trait_renames(item: &Item) -> Vec<(Ident, Ident)> {
     out
 }
 
-fn is_local_impl_bound_target(ty: &Type, impl_type_params: &HashSet<Ident>) -> bool {
-    let Type::Path(TypePath { qself: None, path }) = ty else {
-        return false;
-    };
-    if path.segments.len() != 1 {
-        return false;
+/// How an impl asked for its qualified references to #[decycle] traits to be treated, via
+/// `#[decycle(ranked)]` / `#[decycle(unranked)]` on the impl itself.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum QualifiedRanking {
+    /// No marker: qualified references stay un-ranked, and each is linted.
+    Unmarked,
+    /// `#[decycle(ranked)]`: each is rewritten to the bare trait name, so it's ranked.
+    Ranked,
+    /// `#[decycle(unranked)]`: the deliberate opt-out, kept as written and not linted.
+    Unranked,
+}
+
+/// Removes a `#[decycle(ranked)]` / `#[decycle(unranked)]` marker from an impl's attributes. An
+/// `erase` among its options is left for `finalize`, as `#[decycle(erase)]`.
+fn take_ranking_marker(attrs: &mut Vec<Attribute>, decycle_crate: &Ident) -> QualifiedRanking {
+    let mut marker = QualifiedRanking::Unmarked;
+    let mut erase = false;
+    attrs.retain(|attr| {
+        if !crate::is_decycle_attribute(attr, decycle_crate) {
+            return true;
+        }
+        let args = attr
+            .parse_args_with(punctuated::Punctuated::<Ident, Token![,]>::parse_terminated)
+            .unwrap_or_else(|_| {
+                abort!(
+                    attr,
+                    "expected `#[decycle(ranked)]`, `#[decycle(unranked)]`, \
+                     `#[decycle(erase)]` or `#[decycle(expand_first)]` on an impl"
+                )
+            });
+        for arg in args {
+            match arg.to_string().as_str() {
+                "ranked" => marker = QualifiedRanking::Ranked,
+                "unranked" => marker = QualifiedRanking::Unranked,
+                "erase" => erase = true,
+                _ => abort!(arg, "expected `ranked`, `unranked` or `erase`"),
+            }
+        }
+        false
+    });
+    if erase {
+        attrs.push(parse_quote!(#[decycle(erase)]));
     }
-    let ident = &path.segments[0].ident;
-    ident == "Self" || impl_type_params.contains(ident)
+    marker
 }
 
-fn has_assoc_constraints(path: &Path) -> bool {
-    let Some(last_segment) = path.segments.last() else {
-        return false;
+/// `(path, local name)` for every trait a `#[decycle] use` item brings in: `use super::{Ca,
+/// Cb as B}` gives `super::Ca` as `Ca` and `super::Cb` as `B`.
+fn collect_import_paths(item: &Item) -> Vec<(Path, Ident)> {
+    fn walk(tree: &UseTree, prefix: &Path, out: &mut Vec<(Path, Ident)>) {
+        let mut push = |ident: &Ident, local: &Ident| {
+            let mut path = prefix.clone();
+            path.segments.push(ident.clone().into());
+            out.push((path, local.clone()));
+        };
+        match tree {
+            UseTree::Path(UsePath { ident, tree, .. }) => {
+                let mut prefix = prefix.clone();
+                prefix.segments.push(ident.clone().into());
+                walk(tree, &prefix, out);
+            }
+            UseTree::Name(UseName { ident }) => push(ident, ident),
+            UseTree::Rename(UseRename { ident, rename, .. }) => push(ident, rename),
+            UseTree::Glob(_) => (),
+            UseTree::Group(UseGroup { items, .. }) => {
+                for item in items {
+                    walk(item, prefix, out);
+                }
+            }
+        }
+    }
+    let Item::Use(ItemUse {
+        leading_colon,
+        tree,
+        ..
+    }) = item
+    else {
+        return Vec::new();
     };
-    let PathArguments::AngleBracketed(args) = &last_segment.arguments else {
-        return false;
+    let prefix = Path {
+        leading_colon: *leading_colon,
+        segments: punctuated::Punctuated::new(),
     };
-    args.args.iter().any(|arg| {
-        matches!(
-            arg,
-            GenericArgument::AssocType(_)
-                | GenericArgument::AssocConst(_)
-                | GenericArgument::Constraint(_)
-        )
-    })
+    let mut out = Vec::new();
+    walk(tree, &prefix, &mut out);
+    out
 }
 
-/// The help text must name exactly what `is_local_impl_bound_target` accepts: `Self`, or
-/// one of THIS impl's own generic type parameters — not any type merely defined inside the
-/// `#[decycle]` module (a module-local struct/enum bound target is not accepted by the
-/// check, so the old text promised something the rule didn't actually allow).
-fn local_types_help_message(impl_type_params: &HashSet<Ident>) -> String {
-    if impl_type_params.is_empty() {
-        return "use `Self` or one of this `impl`'s own type parameters".to_owned();
-    }
-    let mut params: Vec<&Ident> = impl_type_params.iter().collect();
-    params.sort_by_key(|ident| ident.to_string());
-    let types = params
-        .iter()
-        .map(|ident| format!("`{ident}`"))
-        .collect::<Vec<_>>()
-        .join(", ");
-    format!("use `Self` or one of this `impl`'s own type parameters, such as {types}")
+/// The qualified paths that name one of the module's #[decycle] traits: `super::<mod>::X` or
+/// `crate::…::<mod>::X` for a trait declared here, and the `#[decycle] use` path for an
+/// imported one. Any other qualified path names something else, and is left alone.
+struct ModuleTraits<'a> {
+    module: &'a Ident,
+    declared: HashSet<Ident>,
+    imported: Vec<(Path, Ident)>,
 }
 
-fn validate_impl_where_bounds(item_impl: &ItemImpl, all_traits: &HashSet<Ident>) {
-    let impl_type_params: HashSet<Ident> = item_impl
-        .generics
-        .params
-        .iter()
-        .filter_map(|param| match param {
-            GenericParam::Type(ty) => Some(ty.ident.clone()),
-            _ => None,
+impl ModuleTraits<'_> {
+    /// The name `path` (post-`self::`) is known by in the module, if it is a qualified
+    /// reference to one of its #[decycle] traits.
+    fn resolve(&self, path: &Path) -> Option<Ident> {
+        let mut segments: Vec<_> = path.segments.iter().collect();
+        let last = segments.pop()?;
+        if segments.is_empty() || segments.iter().any(|seg| !seg.arguments.is_none()) {
+            return None;
+        }
+        let prefix: Vec<_> = segments.iter().map(|seg| &seg.ident).collect();
+        let in_module = path.leading_colon.is_none()
+            && prefix.last() == Some(&self.module)
+            && match prefix.as_slice() {
+                [first, _] if *first == "super" => true,
+                [first, ..] => *first == "crate",
+                [] => false,
+            };
+        if in_module && self.declared.contains(&last.ident) {
+            return Some(last.ident.clone());
+        }
+        self.imported.iter().find_map(|(import, local)| {
+            (import.leading_colon.is_some() == path.leading_colon.is_some()
+                && import.segments.len() == path.segments.len()
+                && import
+                    .segments
+                    .iter()
+                    .zip(&path.segments)
+                    .all(|(a, b)| a.ident == b.ident))
+            .then(|| local.clone())
         })
-        .collect();
+    }
+}
 
-    let Some(where_clause) = &item_impl.generics.where_clause else {
-        return;
-    };
+/// Applies `marker` to `path` if it is a still-qualified (post-`self::`) reference to one of
+/// the module's #[decycle] traits: collapses it to the trait's local name (`Ranked`), or
+/// returns a lint for it (`Unmarked`).
+fn apply_ranking_marker(
+    path: &mut Path,
+    module_traits: &ModuleTraits,
+    marker: QualifiedRanking,
+) -> Option<TokenStream> {
+    crate::helper::strip_leading_self(path);
+    if path.segments.len() < 2 {
+        return None;
+    }
+    let local = module_traits.resolve(path)?;
+    match marker {
+        QualifiedRanking::Ranked => {
+            let mut last = path.segments.last().unwrap().clone();
+            last.ident = local;
+            *path = Path {
+                leading_colon: None,
+                segments: core::iter::once(last).collect(),
+            };
+            None
+        }
+        QualifiedRanking::Unranked => None,
+        QualifiedRanking::Unmarked => Some(qualified_reference_lint(path)),
+    }
+}
 
-    for pred in &where_clause.predicates {
-        let WherePredicate::Type(PredicateType {
-            bounded_ty, bounds, ..
-        }) = pred
-        else {
+/// A warning on `path` that also fires on stable (`emit_warning!` is nightly-only): a use of
+/// a `#[deprecated]` marker, spanned at the reference.
+fn qualified_reference_lint(path: &Path) -> TokenStream {
+    let trait_ident = &path.segments.last().unwrap().ident;
+    let note = format!(
+        "`{}` names a #[decycle] trait through a qualified path, so it is NOT ranked; add \
+         `#[decycle(ranked)]` to the impl to rank it, or `#[decycle(unranked)]` to keep the \
+         original trait",
+        quote!(#path).to_string().replace(' ', "")
+    );
+    let marker = Ident::new(
+        &format!("qualified_decycle_trait_{trait_ident}"),
+        path.segments.last().unwrap().ident.span(),
+    );
+    quote! {
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_camel_case_types)]
+            struct #marker;
+            let _ = #marker;
+        };
+    }
+}
+
+/// Applies each impl's ranking marker to its qualified #[decycle] trait references — the
+/// impl's own trait path, and (for an impl of a #[decycle] trait) its bounds — and returns
+/// the lints for the unmarked ones.
+fn resolve_qualified_references(
+    contents: &mut [Item],
+    all_traits: &HashSet<Ident>,
+    module_traits: &ModuleTraits,
+    decycle_crate: &Ident,
+) -> Vec<TokenStream> {
+    struct Bounds<'a> {
+        module_traits: &'a ModuleTraits<'a>,
+        marker: QualifiedRanking,
+        lints: &'a mut Vec<TokenStream>,
+    }
+    impl syn::visit_mut::VisitMut for Bounds<'_> {
+        fn visit_trait_bound_mut(&mut self, bound: &mut TraitBound) {
+            self.lints.extend(apply_ranking_marker(
+                &mut bound.path,
+                self.module_traits,
+                self.marker,
+            ));
+            syn::visit_mut::visit_trait_bound_mut(self, bound);
+        }
+    }
+    let mut lints = Vec::new();
+    for item in contents.iter_mut() {
+        let Item::Impl(item_impl) = item else {
             continue;
         };
-        if is_local_impl_bound_target(bounded_ty, &impl_type_params) {
+        let marker = take_ranking_marker(&mut item_impl.attrs, decycle_crate);
+        let Some((_, trait_path, _)) = &mut item_impl.trait_ else {
             continue;
+        };
+        lints.extend(apply_ranking_marker(trait_path, module_traits, marker));
+        if trait_path.segments.len() == 1 && all_traits.contains(&trait_path.segments[0].ident) {
+            syn::visit_mut::VisitMut::visit_generics_mut(
+                &mut Bounds {
+                    module_traits,
+                    marker,
+                    lints: &mut lints,
+                },
+                &mut item_impl.generics,
+            );
         }
-        for bound in bounds {
-            let TypeParamBound::Trait(TraitBound { path, .. }) = bound else {
-                continue;
-            };
-            let mut path = path.clone();
+    }
+    lints
+}
+
+/// The name an item declares in its block, for those `hoist_const_block_impls` leaves there.
+fn block_local_name(item: &Item) -> Option<&Ident> {
+    match item {
+        Item::Struct(ItemStruct { ident, .. })
+        | Item::Enum(ItemEnum { ident, .. })
+        | Item::Union(ItemUnion { ident, .. })
+        | Item::Type(ItemType { ident, .. })
+        | Item::Trait(ItemTrait { ident, .. })
+        | Item::TraitAlias(ItemTraitAlias { ident, .. })
+        | Item::Fn(ItemFn {
+            sig: Signature { ident, .. },
+            ..
+        })
+        | Item::Static(ItemStatic { ident, .. })
+        | Item::Mod(ItemMod { ident, .. }) => Some(ident),
+        Item::Const(ItemConst { ident, .. }) if ident != "_" => Some(ident),
+        Item::Macro(ItemMacro { ident, .. }) => ident.as_ref(),
+        _ => None,
+    }
+}
+
+/// The first name of `locals` that `item_impl` refers to: by a path's first segment, or by an
+/// identifier in a macro's arguments other than a method or field name.
+fn block_local_reference<'a>(item_impl: &ItemImpl, locals: &'a [Ident]) -> Option<(Span, &'a Ident)> {
+    use syn::visit::Visit;
+    struct Visitor<'a> {
+        locals: &'a [Ident],
+        found: Option<(Span, &'a Ident)>,
+    }
+    impl<'a> Visitor<'a> {
+        fn check(&mut self, ident: &Ident) {
+            if self.found.is_none() {
+                self.found = self
+                    .locals
+                    .iter()
+                    .find(|local| *local == ident)
+                    .map(|local| (ident.span(), local));
+            }
+        }
+        fn check_tokens(&mut self, tokens: TokenStream) {
+            let mut after_dot = false;
+            for tt in tokens {
+                match &tt {
+                    proc_macro2::TokenTree::Group(group) => self.check_tokens(group.stream()),
+                    proc_macro2::TokenTree::Ident(ident) if !after_dot => self.check(ident),
+                    _ => (),
+                }
+                after_dot = matches!(&tt, proc_macro2::TokenTree::Punct(p) if p.as_char() == '.');
+            }
+        }
+    }
+    impl<'ast> Visit<'ast> for Visitor<'_> {
+        fn visit_path(&mut self, path: &'ast Path) {
+            if path.leading_colon.is_none() {
+                self.check(&path.segments[0].ident);
+            }
+            syn::visit::visit_path(self, path);
+        }
+        fn visit_macro(&mut self, mac: &'ast Macro) {
+            self.check_tokens(mac.tokens.clone());
+            syn::visit::visit_macro(self, mac);
+        }
+    }
+    let mut visitor = Visitor {
+        locals,
+        found: None,
+    };
+    visitor.visit_item_impl(item_impl);
+    visitor.found
+}
+
+/// Moves the impls of #[decycle] traits out of the module's `const _: () = { .. };` blocks
+/// (as derive and attribute macros emit them), nested ones included, to just after the
+/// block, where `finalize` ranks them like any other. Only bare (`impl Cb for B`) or
+/// `#[decycle(ranked)]`-marked ones move: the rest of the block, and the names it declares,
+/// stay put. The `use` and `extern crate` items of the blocks around a moved impl go with it,
+/// so it still names what it did; the block keeps its own. A moved impl naming anything else the
+/// blocks declare (`struct Helper;`) is rejected, since that stays behind.
+fn hoist_const_block_impls(
+    contents: &mut Vec<Item>,
+    all_traits: &HashSet<Ident>,
+    module_traits: &ModuleTraits,
+    decycle_crate: &Ident,
+) {
+    #[allow(clippy::too_many_arguments)]
+    fn hoist(
+        block: &mut Block,
+        scope: &[Item],
+        locals: &[Ident],
+        all_traits: &HashSet<Ident>,
+        module_traits: &ModuleTraits,
+        decycle_crate: &Ident,
+        hoisted: &mut Vec<Item>,
+        carried: &mut Vec<Item>,
+    ) {
+        let mut scope = scope.to_vec();
+        scope.extend(block.stmts.iter().filter_map(|stmt| match stmt {
+            Stmt::Item(item @ (Item::Use(_) | Item::ExternCrate(_))) => Some(item.clone()),
+            _ => None,
+        }));
+        let mut locals = locals.to_vec();
+        locals.extend(block.stmts.iter().filter_map(|stmt| match stmt {
+            Stmt::Item(item) => block_local_name(item).cloned(),
+            _ => None,
+        }));
+        let count = hoisted.len();
+        let stmts = std::mem::take(&mut block.stmts);
+        for mut stmt in stmts {
+            match &mut stmt {
+                Stmt::Item(Item::Impl(item_impl)) => {
+                    if let Some((_, trait_path, _)) = &item_impl.trait_ {
+                        let mut trait_path = trait_path.clone();
+                        crate::helper::strip_leading_self(&mut trait_path);
+                        let bare = trait_path.segments.len() == 1
+                            && all_traits.contains(&trait_path.segments[0].ident);
+                        let marked = item_impl
+                            .attrs
+                            .iter()
+                            .any(|attr| crate::is_decycle_attribute(attr, decycle_crate));
+                        if bare || (marked && module_traits.resolve(&trait_path).is_some()) {
+                            if let Some((span, local)) = block_local_reference(item_impl, &locals)
+                            {
+                                abort!(
+                                    span,
+                                    "decycle: this impl of `{}` is moved out of its `const _` \
+                                     block to be ranked, but `{}` is declared in the block and \
+                                     stays there",
+                                    quote!(#trait_path).to_string().replace(' ', ""),
+                                    local;
+                                    note = "only the block's `use` and `extern crate` items \
+                                            are copied out along with the impl";
+                                    hint = "declare `{}` outside the `const _` block", local
+                                )
+                            }
+                            hoisted.push(Item::Impl(item_impl.clone()));
+                            carried.extend(scope.iter().cloned());
+                            continue;
+                        }
+                    }
+                }
+                Stmt::Item(Item::Const(item_const)) if item_const.ident == "_" => {
+                    if let Expr::Block(expr_block) = &mut *item_const.expr {
+                        hoist(
+                            &mut expr_block.block,
+                            &scope,
+                            &locals,
+                            all_traits,
+                            module_traits,
+                            decycle_crate,
+                            hoisted,
+                            carried,
+                        );
+                    }
+                }
+                _ => (),
+            }
+            block.stmts.push(stmt);
+        }
+        // What only the moved impls used is now unused here.
+        if hoisted.len() > count {
+            for stmt in &mut block.stmts {
+                if let Stmt::Item(Item::Use(ItemUse { attrs, .. })) = stmt {
+                    attrs.push(parse_quote!(#[allow(unused_imports)]));
+                }
+            }
+        }
+    }
+    // An item as compared for duplicates: a carried `use core::fmt;` that the module (or an
+    // earlier block) already has would be a second definition of `fmt`.
+    fn key(item: &Item) -> String {
+        let mut item = item.clone();
+        match &mut item {
+            Item::Use(ItemUse { attrs, vis, .. })
+            | Item::ExternCrate(ItemExternCrate { attrs, vis, .. }) => {
+                attrs.clear();
+                *vis = Visibility::Inherited;
+            }
+            _ => (),
+        }
+        quote!(#item).to_string()
+    }
+    let mut seen: HashSet<String> = contents.iter().map(key).collect();
+    let items = std::mem::take(contents);
+    for mut item in items {
+        let mut hoisted = Vec::new();
+        let mut carried = Vec::new();
+        if let Item::Const(item_const) = &mut item {
+            if item_const.ident == "_" {
+                if let Expr::Block(expr_block) = &mut *item_const.expr {
+                    hoist(
+                        &mut expr_block.block,
+                        &[],
+                        &[],
+                        all_traits,
+                        module_traits,
+                        decycle_crate,
+                        &mut hoisted,
+                        &mut carried,
+                    );
+                }
+            }
+        }
+        contents.push(item);
+        for mut item in carried {
+            if seen.insert(key(&item)) {
+                if let Item::Use(ItemUse { attrs, vis, .. })
+                | Item::ExternCrate(ItemExternCrate { attrs, vis, .. }) = &mut item
+                {
+                    *vis = Visibility::Inherited;
+                    attrs.push(parse_quote!(#[allow(unused_imports)]));
+                }
+                contents.push(item);
+            }
+        }
+        contents.extend(hoisted);
+    }
+}
+
+/// Expands the module's `type` aliases (`type E = Expr<Span>;`, generic ones with their
+/// arguments or defaults) wherever `finalize` compares types syntactically: an impl's header,
+/// bounds and signatures (`where E: Cb` against `impl Cb for Expr<Span>`), or a helper fn's
+/// signature. Bodies keep them, and so does a name the impl's or method's own generic
+/// parameters shadow (`impl<T> Ca for W<T>` next to `type T = u8;`).
+struct ExpandAliases<'a> {
+    aliases: &'a HashMap<Ident, ItemType>,
+    /// The aliases expanded somewhere.
+    expanded: HashSet<Ident>,
+    expanding: Vec<Ident>,
+    /// The type and const params in scope, innermost last.
+    shadowed: Vec<Vec<Ident>>,
+}
+
+impl ExpandAliases<'_> {
+    /// Visits `generics` and then `rest` with `generics`' type and const params in scope.
+    fn with_params(&mut self, generics: &Generics, rest: impl FnOnce(&mut Self)) {
+        self.shadowed.push(
+            generics
+                .params
+                .iter()
+                .filter_map(|p| match p {
+                    GenericParam::Type(t) => Some(t.ident.clone()),
+                    GenericParam::Const(c) => Some(c.ident.clone()),
+                    GenericParam::Lifetime(_) => None,
+                })
+                .collect(),
+        );
+        rest(self);
+        self.shadowed.pop();
+    }
+
+    /// `alias`'s type with its parameters replaced by `args`, positionally per kind: a missing
+    /// lifetime is elided, a missing type or const takes its default.
+    fn instantiate(alias: &ItemType, args: &[GenericArgument]) -> Type {
+        let mut subst = AliasSubst::default();
+        let mut lts = args.iter().filter_map(|arg| match arg {
+            GenericArgument::Lifetime(lt) => Some(lt.clone()),
+            _ => None,
+        });
+        let mut others = args.iter().filter(|arg| {
+            matches!(arg, GenericArgument::Type(_) | GenericArgument::Const(_))
+        });
+        for param in &alias.generics.params {
+            match param {
+                GenericParam::Lifetime(l) => {
+                    let lt = lts.next().unwrap_or_else(|| Lifetime::new("'_", l.lifetime.span()));
+                    subst.lifetimes.insert(l.lifetime.ident.clone(), lt);
+                }
+                GenericParam::Type(t) => {
+                    let ty = match others.next() {
+                        Some(GenericArgument::Type(ty)) => ty.clone(),
+                        Some(GenericArgument::Const(expr)) => Type::Verbatim(quote!(#expr)),
+                        _ => match &t.default {
+                            Some(default) => {
+                                let mut default = default.clone();
+                                syn::visit_mut::VisitMut::visit_type_mut(&mut subst, &mut default);
+                                default
+                            }
+                            None => abort!(t, "missing argument for this alias parameter"),
+                        },
+                    };
+                    subst.types.insert(t.ident.clone(), ty);
+                }
+                GenericParam::Const(c) => {
+                    let expr: Expr = match others.next() {
+                        Some(GenericArgument::Type(ty)) => parse_quote!(#ty),
+                        Some(GenericArgument::Const(expr)) => expr.clone(),
+                        _ => match &c.default {
+                            Some(default) => default.clone(),
+                            None => abort!(c, "missing argument for this alias parameter"),
+                        },
+                    };
+                    subst.consts.insert(c.ident.clone(), expr);
+                }
+            }
+        }
+        let mut ty = (*alias.ty).clone();
+        syn::visit_mut::VisitMut::visit_type_mut(&mut subst, &mut ty);
+        ty
+    }
+}
+
+impl syn::visit_mut::VisitMut for ExpandAliases<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        syn::visit_mut::visit_type_mut(self, ty);
+        let Type::Path(TypePath { qself: None, path }) = ty else {
+            return;
+        };
+        let mut path = path.clone();
+        crate::helper::strip_leading_self(&mut path);
+        if path.leading_colon.is_some() || path.segments.len() != 1 {
+            return;
+        }
+        let seg = &path.segments[0];
+        let Some(alias) = self.aliases.get(&seg.ident) else {
+            return;
+        };
+        if self.shadowed.iter().flatten().any(|param| *param == seg.ident) {
+            return;
+        }
+        // A self-referential alias is rustc's to reject.
+        if self.expanding.contains(&seg.ident) {
+            return;
+        }
+        let args: Vec<GenericArgument> = match &seg.arguments {
+            PathArguments::None => Vec::new(),
+            PathArguments::AngleBracketed(ab) => ab.args.iter().cloned().collect(),
+            PathArguments::Parenthesized(_) => return,
+        };
+        let mut expanded = Self::instantiate(alias, &args);
+        self.expanded.insert(seg.ident.clone());
+        self.expanding.push(seg.ident.clone());
+        self.visit_type_mut(&mut expanded);
+        self.expanding.pop();
+        *ty = expanded;
+    }
+
+    fn visit_item_impl_mut(&mut self, item_impl: &mut ItemImpl) {
+        let generics = item_impl.generics.clone();
+        self.with_params(&generics, |this| syn::visit_mut::visit_item_impl_mut(this, item_impl));
+    }
+
+    fn visit_signature_mut(&mut self, sig: &mut Signature) {
+        let generics = sig.generics.clone();
+        self.with_params(&generics, |this| syn::visit_mut::visit_signature_mut(this, sig));
+    }
+
+    fn visit_block_mut(&mut self, _: &mut Block) {}
+}
+
+/// An alias's parameters ⇒ the arguments it's used with.
+#[derive(Default)]
+struct AliasSubst {
+    lifetimes: HashMap<Ident, Lifetime>,
+    types: HashMap<Ident, Type>,
+    consts: HashMap<Ident, Expr>,
+}
+
+impl syn::visit_mut::VisitMut for AliasSubst {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if let Some(ident) = path.get_ident() {
+                if let Some(rep) = self.types.get(ident) {
+                    *ty = rep.clone();
+                    return;
+                }
+                // A const parameter passed on (`Buf<N>`) parses as a type.
+                if let Some(rep) = self.consts.get(ident) {
+                    *ty = Type::Verbatim(quote!({ #rep }));
+                    return;
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(ExprPath { qself: None, path, .. }) = expr {
+            if let Some(rep) = path.get_ident().and_then(|ident| self.consts.get(ident)) {
+                *expr = parse_quote!((#rep));
+                return;
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_lifetime_mut(&mut self, lt: &mut Lifetime) {
+        if let Some(rep) = self.lifetimes.get(&lt.ident) {
+            *lt = rep.clone();
+        }
+    }
+}
+
+/// Whether `item` is an inherent impl or a free fn with a bound naming one of `all_traits` —
+/// in its (or a method's) generics, or an `impl Trait` argument.
+fn has_cyclic_bound(item: &Item, all_traits: &HashSet<Ident>) -> bool {
+    use syn::visit::Visit;
+    struct Finder<'a>(&'a HashSet<Ident>, bool);
+    impl<'ast> Visit<'ast> for Finder<'_> {
+        fn visit_trait_bound(&mut self, bound: &'ast TraitBound) {
+            let mut path = bound.path.clone();
             crate::helper::strip_leading_self(&mut path);
-            // Single-segment match only (after self::-normalization) — a multi-segment
-            // path (`some::mod::Foo`) merely sharing a last segment with a #[decycle]
-            // trait is a DIFFERENT item; matching on the last segment alone was a false
-            // positive. NOTE: a still-multi-segment, qualified reference to a #[decycle]
-            // trait (`super::Foo`, `crate::mod::Foo`) is intentionally NOT flagged here —
-            // it's the established, working way to bind a FOREIGN (non-cyclic) type to
-            // the ORIGINAL, un-ranked trait in a side-bound (`Foreign: super::Foo`); only
-            // the bare/`self::`-qualified form participates in ranking at all, so there's
-            // no reliable syntactic way to tell "meant to be ranked, mis-qualified" apart
-            // from this deliberate opt-out.
-            if path.segments.len() != 1 {
-                continue;
+            self.1 |= path.segments.len() == 1 && self.0.contains(&path.segments[0].ident);
+            syn::visit::visit_trait_bound(self, bound);
+        }
+        // `dyn Trait` is a type, not a bound on the helper.
+        fn visit_type_trait_object(&mut self, _: &'ast TypeTraitObject) {}
+        fn visit_block(&mut self, _: &'ast Block) {}
+    }
+    let mut finder = Finder(all_traits, false);
+    match item {
+        Item::Impl(item_impl) if item_impl.trait_.is_none() => {
+            finder.visit_generics(&item_impl.generics);
+            for impl_item in &item_impl.items {
+                if let ImplItem::Fn(method) = impl_item {
+                    finder.visit_signature(&method.sig);
+                }
             }
-            let last_segment = &path.segments[0];
-            if all_traits.contains(&last_segment.ident) && has_assoc_constraints(&path) {
-                // F6: the earlier wording ("...on non-local type") described what the check
-                // rejects, but the check doesn't actually key on locality — a bound on a
-                // module-LOCAL struct/enum (anything other than `Self` or one of this impl's
-                // own type parameters) is rejected exactly the same way. State what's
-                // ACCEPTED instead, which is unambiguous either way.
-                let help_message = local_types_help_message(&impl_type_params);
-                abort!(
-                    path,
-                    "associated-type constraints in #[decycle] impl where-clauses are only supported on `Self` or the impl's own type parameters";
-                    help = bounded_ty.span() => "{}", help_message
-                );
+        }
+        Item::Fn(item_fn) => finder.visit_signature(&item_fn.sig),
+        _ => (),
+    }
+    finder.1
+}
+
+/// The top-level options of a `#[decycle(..)]` attribute, each as its tokens (`dyn`,
+/// `forward(Box)`, `expand_first`).
+fn decycle_options(attr: &Attribute) -> Vec<TokenStream> {
+    let Meta::List(list) = &attr.meta else {
+        return Vec::new();
+    };
+    let mut options = vec![TokenStream::new()];
+    for tt in list.tokens.clone() {
+        match &tt {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                options.push(TokenStream::new())
             }
+            _ => options.last_mut().unwrap().extend([tt]),
         }
     }
+    options.retain(|option| !option.is_empty());
+    options
+}
+
+fn is_expand_first_option(option: &TokenStream) -> bool {
+    option.to_string() == "expand_first"
+}
+
+/// Whether `item` is a trait or an impl marked `#[decycle(expand_first)]`.
+fn is_expand_first(item: &Item, decycle_crate: &Ident) -> bool {
+    let attrs = match item {
+        Item::Trait(item_trait) => &item_trait.attrs,
+        Item::Impl(item_impl) => &item_impl.attrs,
+        _ => return false,
+    };
+    attrs.iter().any(|attr| {
+        crate::is_decycle_attribute(attr, decycle_crate)
+            && decycle_options(attr).iter().any(is_expand_first_option)
+    })
+}
+
+/// The attributes of `item`, for the kinds an attribute macro may expand to.
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(ItemConst { attrs, .. })
+        | Item::Enum(ItemEnum { attrs, .. })
+        | Item::ExternCrate(ItemExternCrate { attrs, .. })
+        | Item::Fn(ItemFn { attrs, .. })
+        | Item::ForeignMod(ItemForeignMod { attrs, .. })
+        | Item::Impl(ItemImpl { attrs, .. })
+        | Item::Macro(ItemMacro { attrs, .. })
+        | Item::Mod(ItemMod { attrs, .. })
+        | Item::Static(ItemStatic { attrs, .. })
+        | Item::Struct(ItemStruct { attrs, .. })
+        | Item::Trait(ItemTrait { attrs, .. })
+        | Item::TraitAlias(ItemTraitAlias { attrs, .. })
+        | Item::Type(ItemType { attrs, .. })
+        | Item::Union(ItemUnion { attrs, .. })
+        | Item::Use(ItemUse { attrs, .. }) => Some(attrs),
+        _ => None,
+    }
+}
+
+/// A module on its way through `#[decycle(expand_first)]` items' attribute macros: the
+/// module's arguments, and its contents with `module.content[index]` the item being expanded.
+pub struct ExpandFirstArgs {
+    pub decycle: Path,
+    pub recurse_level: usize,
+    pub support_infinite_cycle: bool,
+    pub index: usize,
+    pub module: ItemMod,
+}
+
+impl parse::Parse for ExpandFirstArgs {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let decycle = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let recurse_level = input.parse::<LitInt>()?.base10_parse()?;
+        input.parse::<Token![,]>()?;
+        let support_infinite_cycle = input.parse::<LitBool>()?.value;
+        input.parse::<Token![,]>()?;
+        let index = input.parse::<LitInt>()?.base10_parse()?;
+        input.parse::<Token![,]>()?;
+        let module = input.parse()?;
+        Ok(ExpandFirstArgs {
+            decycle,
+            recurse_level,
+            support_infinite_cycle,
+            index,
+            module,
+        })
+    }
+}
+
+impl template_quote::ToTokens for ExpandFirstArgs {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let recurse_level = self.recurse_level;
+        let support_infinite_cycle = self.support_infinite_cycle;
+        let index = self.index;
+        tokens.extend(quote! {
+            #{&self.decycle}, #recurse_level, #support_infinite_cycle, #index, #{&self.module}
+        });
+    }
+}
+
+/// The item being expanded, its attribute macros followed by `#[decycle::__expand_first]`,
+/// which runs last and hands their output to [`resume_expand_first`]. Its `cfg`s wait on the
+/// carrier, so that the module rides along whether or not they hold, and so do its decycle
+/// markers, which are only meaningful to `process_module`.
+fn expand_first_carrier(args: ExpandFirstArgs) -> TokenStream {
+    let decycle_crate = &args.decycle.segments.first().unwrap().ident;
+    let mut item = args.module.content.as_ref().unwrap().1[args.index].clone();
+    let attrs = item_attrs_mut(&mut item).unwrap();
+    attrs.retain(|attr| {
+        !attr.path().is_ident("cfg") && !crate::is_decycle_attribute(attr, decycle_crate)
+    });
+    let decycle = &args.decycle;
+    attrs.push(parse_quote!(#[#decycle::__expand_first(#args)]));
+    quote!(#item)
+}
+
+/// The `#[decycle::__expand_first]` carrier: puts `expanded`, the output of an item's attribute
+/// macros, back in its place in the module, with the item's `cfg`s and decycle markers (bar
+/// `expand_first`), then expands the next `#[decycle(expand_first)]` item, or, if that was the
+/// last, decycles the module's body.
+pub fn resume_expand_first(args: ExpandFirstArgs, mut expanded: Item) -> TokenStream {
+    let ExpandFirstArgs {
+        decycle,
+        recurse_level,
+        support_infinite_cycle,
+        index,
+        mut module,
+    } = args;
+    let decycle_crate = decycle.segments.first().unwrap().ident.clone();
+    let contents = &mut module.content.as_mut().unwrap().1;
+    let is_trait = matches!(contents[index], Item::Trait(_));
+    let mut kept: Vec<Attribute> = item_attrs_mut(&mut contents[index])
+        .unwrap()
+        .iter()
+        .filter(|attr| {
+            attr.path().is_ident("cfg") || crate::is_decycle_attribute(attr, &decycle_crate)
+        })
+        .cloned()
+        .collect();
+    for attr in &mut kept {
+        if !crate::is_decycle_attribute(attr, &decycle_crate) {
+            continue;
+        }
+        let options: Vec<_> = decycle_options(attr)
+            .into_iter()
+            .filter(|option| !is_expand_first_option(option))
+            .collect();
+        let path = attr.path().clone();
+        attr.meta = if options.is_empty() {
+            Meta::Path(path)
+        } else {
+            parse_quote!(#path(#(#options),*))
+        };
+    }
+    // A bare `#[decycle]` marks a trait, but means nothing on an impl.
+    if !is_trait {
+        kept.retain(|attr| !matches!(attr.meta, Meta::Path(_)));
+    }
+    if let Some(attrs) = item_attrs_mut(&mut expanded) {
+        kept.append(attrs);
+        *attrs = kept;
+    }
+    contents[index] = expanded;
+    if let Some(index) = contents
+        .iter()
+        .position(|item| is_expand_first(item, &decycle_crate))
+    {
+        return expand_first_carrier(ExpandFirstArgs {
+            decycle,
+            recurse_level,
+            support_infinite_cycle,
+            index,
+            module,
+        });
+    }
+    module_body(
+        &module.ident,
+        contents,
+        &decycle,
+        recurse_level,
+        support_infinite_cycle,
+        |items| items,
+    )
 }
 
 pub fn process_module(
@@ -232,14 +1137,135 @@ pub fn process_module(
     recurse_level: usize,
     support_infinite_cycle: bool,
 ) -> TokenStream {
+    // `#[cfg_attr(pred, decycle)]`: expand the module once for each combination of values of the
+    // `cfg` options its markers depend on, each copy `cfg`-gated on them, so exactly one of them
+    // is compiled; or just once if they depend on none (`all()`, `any()`).
+    let decycle_crate = decycle.segments.first().unwrap().ident.clone();
+    let predicates = module
+        .content
+        .as_mut()
+        .map(|(_, contents)| cfg_attr_marker_predicates(contents, &decycle_crate))
+        .unwrap_or_default();
+    if !predicates.is_empty() {
+        // Should the options be rejected, the module is emitted without its markers, so rustc
+        // doesn't go on to report `decycle` as an unknown attribute.
+        let mut stripped = module.clone();
+        for item in stripped.content.as_mut().unwrap().1.iter_mut() {
+            for attrs in marker_attr_lists(item) {
+                resolve_cfg_attr_marker(attrs, &[], &decycle_crate);
+            }
+        }
+        proc_macro_error::set_dummy(quote!(#stripped));
+        let options = cfg_attr_marker_options(&predicates);
+        return (0..1usize << options.len())
+            .map(|combination| {
+                let values: Vec<(Meta, bool)> = options
+                    .iter()
+                    .enumerate()
+                    .map(|(i, option)| (option.clone(), combination & (1 << i) == 0))
+                    .collect();
+                let mut module = module.clone();
+                for (option, holds) in &values {
+                    module.attrs.push(if *holds {
+                        parse_quote!(#[cfg(#option)])
+                    } else {
+                        parse_quote!(#[cfg(not(#option))])
+                    });
+                }
+                let contents = &mut module.content.as_mut().unwrap().1;
+                for item in contents.iter_mut() {
+                    for attrs in marker_attr_lists(item) {
+                        resolve_cfg_attr_marker(attrs, &values, &decycle_crate);
+                    }
+                }
+                // Without a marker there is nothing left to decycle: the module is then plain.
+                let has_marker = contents.iter().any(|item| match item {
+                    Item::Trait(ItemTrait { attrs, .. })
+                    | Item::TraitAlias(ItemTraitAlias { attrs, .. })
+                    | Item::Use(ItemUse { attrs, .. }) => attrs
+                        .iter()
+                        .any(|attr| crate::is_decycle_attribute(attr, &decycle_crate)),
+                    _ => false,
+                });
+                if has_marker {
+                    process_module(module, decycle, recurse_level, support_infinite_cycle)
+                } else {
+                    quote!(#module)
+                }
+            })
+            .collect();
+    }
     let contents = &mut module
         .content
         .as_mut()
         .unwrap_or_else(|| abort!(&module.semi, "needs content"))
         .1;
+    // `#[decycle(expand_first)]`: the module's `use`s go out now, so the marked item's attribute
+    // macros resolve; the rest rides along on the carrier, and is ranked once they have run.
+    if contents
+        .iter()
+        .any(|item| is_expand_first(item, &decycle_crate))
+    {
+        let (uses, rest): (Vec<_>, Vec<_>) = std::mem::take(contents).into_iter().partition(|item| {
+            matches!(item, Item::Use(ItemUse { attrs, .. }) if !attrs
+                .iter()
+                .any(|attr| crate::is_decycle_attribute(attr, &decycle_crate)))
+        });
+        *contents = rest;
+        let index = contents
+            .iter()
+            .position(|item| is_expand_first(item, &decycle_crate))
+            .unwrap();
+        let carrier = expand_first_carrier(ExpandFirstArgs {
+            decycle: decycle.clone(),
+            recurse_level,
+            support_infinite_cycle,
+            index,
+            module: module.clone(),
+        });
+        return quote! {
+            #(for attr in &module.attrs) { #attr }
+            #{&module.vis} #{&module.unsafety} #{&module.mod_token} #{&module.ident} {
+                #(for item in uses) { #item }
+                #carrier
+            }
+        };
+    }
+    let body = module_body(
+        &module.ident,
+        contents,
+        decycle,
+        recurse_level,
+        support_infinite_cycle,
+        |items| {
+            quote! {
+                #{&module.vis} #{&module.unsafety} mod #{&module.ident} { #items }
+            }
+        },
+    );
+    quote! {
+        #(for attr in &module.attrs) { #attr }
+        #{&module.vis} #{&module.unsafety} #{&module.mod_token} #{&module.ident} {
+            #body
+        }
+    }
+}
+
+/// Decycles a module's `contents`, returning the items of its body. `dummy` wraps the items
+/// `proc_macro_error` emits if it aborts.
+fn module_body(
+    module_ident: &Ident,
+    contents: &mut Vec<Item>,
+    decycle: &Path,
+    recurse_level: usize,
+    support_infinite_cycle: bool,
+    dummy: impl Fn(TokenStream) -> TokenStream,
+) -> TokenStream {
     // The decycle crate name as passed to the macro (leading segment of `decycle = …`, default
     // `decycle`) — used to match `#[<crate>::decycle]` on inner items without reading the manifest.
     let decycle_crate = &decycle.segments.first().unwrap().ident;
+    // The `#[decycle] use` paths, for `ModuleTraits`.
+    let mut imported = Vec::new();
     let (traits, working_list, renames): (Vec<_>, Vec<_>, Vec<_>) = contents.iter_mut().fold(
         Default::default(),
         |(mut traits, mut working_list, mut renames), item| {
@@ -249,19 +1275,43 @@ pub fn process_module(
                 | Item::Use(ItemUse { attrs, .. }) => {
                     // detect and remove #[decycle] attribute
                     let mut old_attrs = std::mem::take(attrs).into_iter();
-                    let mut flag = false;
+                    let mut decycle_attr = None;
                     attrs.extend((&mut old_attrs).take_while(|attr| {
                         if crate::is_decycle_attribute(attr, decycle_crate) {
-                            flag = true;
+                            decycle_attr = Some(attr.clone());
                         }
-                        !flag
+                        decycle_attr.is_none()
                     }));
                     attrs.extend(old_attrs);
-                    if flag {
+                    if let Some(decycle_attr) = decycle_attr {
                         if let Item::Trait(item_trait) = item {
-                            traits.push(item_trait.clone());
+                            let mut item_trait = item_trait.clone();
+                            // `#[decycle(erase(..))]` on a method is only for `finalize`: carried
+                            // on its copy, and not emitted.
+                            crate::carry_method_markers(&mut item_trait, decycle_crate);
+                            if let Item::Trait(emitted) = item {
+                                crate::strip_method_markers(emitted, decycle_crate);
+                            }
+                            // `#[decycle(dyn, forward(...))]`: checked here, and carried to
+                            // `finalize` on its copy of the trait.
+                            if let Meta::List(list) = &decycle_attr.meta {
+                                let options = crate::finalize::parse_trait_options(&decycle_attr);
+                                let tokens = &list.tokens;
+                                item_trait.attrs.push(parse_quote!(#[decycle(#tokens)]));
+                                // `#[decycle(dyn)]`: the vtable entries go on the trait as
+                                // emitted here.
+                                if options.dyn_entries {
+                                    if let Item::Trait(emitted) = item {
+                                        emitted
+                                            .items
+                                            .extend(crate::finalize::dyn_entry_defaults(&item_trait));
+                                    }
+                                }
+                            }
+                            traits.push(item_trait);
                         } else {
                             working_list.extend(process_trait_path(item));
+                            imported.extend(collect_import_paths(item));
                             renames.extend(collect_trait_renames(item));
                         }
                     }
@@ -271,13 +1321,9 @@ pub fn process_module(
             (traits, working_list, renames)
         },
     );
-    proc_macro_error::set_dummy(
-        quote! {
-            #{&module.vis} #{&module.unsafety} mod #{&module.ident} {
-                #(for content in contents.clone()) { #content }
-            }
-        },
-    );
+    proc_macro_error::set_dummy(dummy(quote! {
+        #(for content in contents.clone()) { #content }
+    }));
     for item in contents.iter() {
         match item {
             Item::Mod(item_mod) => {
@@ -299,11 +1345,13 @@ pub fn process_module(
         .filter_map(|path| path.segments.last().map(|seg| seg.ident.clone()))
         .chain(traits.iter().map(|ItemTrait { ident, .. }| ident.clone()))
         .collect();
-    for item in contents.iter() {
-        if let Item::Impl(item_impl) = item {
-            validate_impl_where_bounds(item_impl, &all_traits);
-        }
-    }
+    let module_traits = ModuleTraits {
+        module: module_ident,
+        declared: traits.iter().map(|item_trait| item_trait.ident.clone()).collect(),
+        imported,
+    };
+    hoist_const_block_impls(contents, &all_traits, &module_traits, decycle_crate);
+    let lints = resolve_qualified_references(contents, &all_traits, &module_traits, decycle_crate);
     let (raw_contents, contents): (Vec<_>, Vec<_>) = contents
         .iter()
         .map(|content| {
@@ -318,13 +1366,11 @@ pub fn process_module(
                 crate::helper::strip_leading_self(trait_path);
                 // Check the (self::-normalized) trait_path contains just one segment.
                 // NOTE: a still-qualified trait path (`impl crate::foo::MyTrait for X`,
-                // `impl super::MyTrait for X`) is intentionally left as an ordinary,
-                // non-decycled impl rather than flagged — the same qualified-reference
-                // form is the established way to give a FOREIGN/non-cyclic type an
-                // impl of the ORIGINAL, un-ranked trait from inside a #[decycle] module
-                // (mirrored by the identical, deliberate pattern in where-bounds; see
-                // `validate_impl_where_bounds`), so it can't be reliably distinguished
-                // from a genuine mis-qualification at this syntactic level.
+                // `impl super::MyTrait for X`) is left as an ordinary, non-decycled impl
+                // of the ORIGINAL, un-ranked trait — the way to give a FOREIGN/non-cyclic
+                // type an impl from inside a #[decycle] module. `#[decycle(ranked)]` has
+                // already collapsed it to the bare name if that's not what was meant, and
+                // `resolve_qualified_references` lints it if neither marker was given.
                 if trait_path.segments.len() == 1 {
                     if let Some(seg) = trait_path.segments.first() {
                         if all_traits.contains(&seg.ident) {
@@ -344,11 +1390,48 @@ pub fn process_module(
                 (raw_contents, contents)
             },
         );
+    // Inherent impls and free fns whose bounds name a #[decycle] trait go to `finalize` too,
+    // which keeps them as written and adds a ranked copy for the cycle bodies to call.
+    let (helpers, raw_contents): (Vec<_>, Vec<_>) = raw_contents
+        .into_iter()
+        .partition(|item| has_cyclic_bound(item, &all_traits));
+    let (mut contents, mut fns) = (contents, Vec::new());
+    for item in helpers {
+        match item {
+            Item::Impl(item_impl) => contents.push(item_impl.clone()),
+            Item::Fn(item_fn) => fns.push(item_fn.clone()),
+            _ => unreachable!(),
+        }
+    }
+    let aliases: HashMap<Ident, ItemType> = raw_contents
+        .iter()
+        .filter_map(|item| match item {
+            Item::Type(item_type) => Some((item_type.ident.clone(), item_type.clone())),
+            _ => None,
+        })
+        .collect();
+    let mut expanded_aliases = HashSet::new();
+    if !aliases.is_empty() {
+        let mut expand = ExpandAliases {
+            aliases: &aliases,
+            expanded: HashSet::new(),
+            expanding: Vec::new(),
+            shadowed: Vec::new(),
+        };
+        for item_impl in &mut contents {
+            syn::visit_mut::VisitMut::visit_item_impl_mut(&mut expand, item_impl);
+        }
+        for item_fn in &mut fns {
+            syn::visit_mut::VisitMut::visit_signature_mut(&mut expand, &mut item_fn.sig);
+        }
+        expanded_aliases = expand.expanded;
+    }
     let first_path = working_list.first().cloned();
     let mut args = crate::finalize::FinalizeArgs {
         working_list,
         traits,
         contents,
+        fns,
         recurse_level,
         support_infinite_cycle,
         renames,
@@ -360,18 +1443,22 @@ pub fn process_module(
         decycle_path: None,
     };
     args.working_list.push(parse_quote!(#decycle::__finalize));
+    // An alias only the impls' headers used is no longer used anywhere.
+    let expanded_alias = |item: &Item| {
+        matches!(item, Item::Type(ItemType { ident, .. }) if expanded_aliases.contains(ident))
+    };
     quote! {
-        #(for attr in &module.attrs) { #attr }
-        #{&module.vis} #{&module.unsafety} #{&module.mod_token} #{&module.ident} {
-
-            #(for raw_content in raw_contents) { #raw_content }
+        #(for raw_content in raw_contents) {
+            #(if expanded_alias(raw_content)) { #[allow(dead_code)] }
+            #raw_content
+        }
+        #(for lint in &lints) { #lint }
 
-            #(if let Some(first_path) = first_path) {
-                #first_path! { #args }
-            }
-            #(else) {
-                #{ crate::finalize::finalize(args) }
-            }
+        #(if let Some(first_path) = first_path) {
+            #first_path! { #args }
+        }
+        #(else) {
+            #{ crate::finalize::finalize(args) }
         }
     }
 }
@@ -12,6 +12,7 @@ pub fn process_trait(
     decycle_path: &Path,
     marker_path: Option<&Path>,
     alter_macro_name: Option<&Ident>,
+    local: bool,
     leaker_config: type_leak::LeakerConfig,
 ) -> TokenStream2 {
     let random_suffix = crate::get_random();
@@ -32,20 +33,33 @@ pub fn process_trait(
             trait_item.ident.span(),
         )
     });
+    // `local` (a trait in a fn body): the carrier is a plain `macro_rules!` named after the trait,
+    // reached by textual scope from later in the block. Exported, it would land at the crate root,
+    // where the same trait declared in two fns (two `#[test]`s) defines it twice (E0428).
+    let temporal_mac_name = if local && alter_macro_name.is_none() {
+        trait_item.ident.clone()
+    } else {
+        temporal_mac_name
+    };
     let crate_version = env!("CARGO_PKG_VERSION");
     let crate_identity = LitStr::new(&crate::get_crate_identity(), Span::call_site());
 
+    // `#[decycle(erase(..))]` on a method rides along to `finalize`, but isn't emitted.
+    let decycle_crate = &decycle_path.segments.first().unwrap().ident;
+    let mut emitted_trait_item = trait_item.clone();
+    crate::strip_method_markers(&mut emitted_trait_item, decycle_crate);
     let mut modified_trait_item = trait_item.clone();
+    crate::carry_method_markers(&mut modified_trait_item, decycle_crate);
     // Randomize Ident of GenericParam in modified_trait_item.generics
     let mut renamer =
         crate::randomize_impl_generics(&mut modified_trait_item.generics, random_suffix);
     renamer.visit_item_trait_mut(&mut modified_trait_item);
     let output0 = quote! {
-        #trait_item
+        #emitted_trait_item
 
         #[allow(unused_macros, unused_imports, dead_code, non_local_definitions)]
         #[doc(hidden)]
-        #[macro_export]
+        #(if !local) { #[macro_export] }
         macro_rules! #temporal_mac_name {
             (#crate_identity #crate_version [$_:path, $wl1:path $(,$wl:path)* $(,)?] {$($trait_defs:tt)*} $($t:tt)*) => {
                 $wl1! {
@@ -72,7 +86,8 @@ pub fn process_trait(
             };
         }
 
-        #(if alter_macro_name.is_none()) {
+        #(if local) {
+        } #(else if alter_macro_name.is_none()) {
             #[doc(hidden)]
             #[allow(unused_imports, unused_macros, dead_code)]
             #{&trait_item.vis} use #temporal_mac_name as #{&trait_item.ident};
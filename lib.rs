@@ -2,6 +2,8 @@
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/yasuo-ozu/decycle/main/assets/logo.svg")]
 #![doc = include_str!("README.md")]
 
+#[doc(hidden)]
+pub use decycle_macro::__expand_first;
 #[doc(hidden)]
 pub use decycle_macro::__finalize;
 
@@ -72,38 +74,381 @@ pub use decycle_impl::process_module;
 ///     trait definition contains non-absolute type paths so decycle can intern
 ///     them into a stable, globally reachable form.
 ///   - `decycle`: override the path used to refer to this crate
+///   - `local`: for a trait declared in a fn body (see "Cycles in fn bodies")
 ///
 /// ### Impl where-clause bounds
-/// In `impl` blocks inside a `#[decycle]` module, avoid constraining
-/// `#[decycle]` traits on non-local bounded types with associated constraints.
-/// For example, this is rejected:
+/// In `impl` blocks inside a `#[decycle]` module, a bound on a `#[decycle]`
+/// trait may carry associated-type constraints on any bounded type — `Self`,
+/// one of the `impl`'s own type parameters, or a sibling concrete type:
 ///
-/// ```rust,compile_fail
+/// ```rust
 /// # use decycle::decycle;
 /// #[decycle]
-/// pub trait MyTrait<'a> {
-///     type Assoc;
+/// mod m {
+///     #[decycle]
+///     pub trait Evaluate {
+///         type Output;
+///         fn evaluate(&self) -> Self::Output;
+///     }
+///
+///     pub struct Lit;
+///     pub struct Neg;
+///
+///     impl Evaluate for Neg
+///     where
+///         Lit: Evaluate<Output = i64>,
+///     {
+///         type Output = i64;
+///         fn evaluate(&self) -> i64 {
+///             -Lit.evaluate()
+///         }
+///     }
+///
+///     impl Evaluate for Lit
+///     where
+///         Neg: Evaluate<Output = i64>,
+///     {
+///         type Output = i64;
+///         fn evaluate(&self) -> i64 {
+///             1
+///         }
+///     }
 /// }
+/// # fn main() {}
+/// ```
+///
+/// The constraints are checked on the trait's rank-free types trait, so they
+/// hold at every rank.
+///
+///
+/// ### Qualified trait paths
+/// Only a bare (or `self::`) reference to a `#[decycle]` trait is ranked. A
+/// qualified one (`impl crate::m::Ca for X`, `where B: super::m::Ca`, or the
+/// path of a `#[decycle] use`) names the original trait, which is how a type
+/// outside the cycle is given an impl or a bound on it. Such references are
+/// linted (a `deprecated` warning) unless the impl says which one is meant:
+///
+/// - `#[decycle(ranked)]` on the impl ranks its qualified references like bare ones.
+/// - `#[decycle(unranked)]` keeps them on the original trait, without the lint.
+///
+/// A path into some other module (`u8: crate::other::Ca`) names that module's
+/// trait, whatever its name, and is neither linted nor ranked.
+///
 ///
+/// ### Forwarding through pointers
+/// A hand-written `impl<T: Evaluate + ?Sized> Evaluate for Box<T>` outside the
+/// cycle is reached through the original trait, so every `Box` hop restarts
+/// the rank count. Instead, list the wrappers on the trait:
+///
+/// ```rust
+/// # use decycle::decycle;
 /// #[decycle]
 /// mod m {
-///     #[decycle]
-///     use super::MyTrait;
+///     #[decycle(forward(Box, &))]
+///     pub trait Evaluate {
+///         fn evaluate(&self) -> i64;
+///     }
 ///
-///     pub struct MyStruct<T>(::core::marker::PhantomData<T>);
+///     pub enum Expr {
+///         Lit(i64),
+///         Neg(Box<Expr>),
+///     }
 ///
-///     impl<'a, T> MyTrait<'a> for MyStruct<T>
+///     impl Evaluate for Expr
 ///     where
-///         (): MyTrait<'a, Assoc = T>,
+///         Box<Expr>: Evaluate,
 ///     {
-///         type Assoc = T;
+///         fn evaluate(&self) -> i64 {
+///             match self {
+///                 Expr::Lit(n) => *n,
+///                 Expr::Neg(e) => -e.evaluate(),
+///             }
+///         }
 ///     }
 /// }
 /// # fn main() {}
 /// ```
 ///
-/// Prefer `Self` or one of the `impl`'s own type parameters as the bounded
-/// type in such constraints.
+/// `forward(...)` takes `Box`, `Rc`, `Arc`, `&`, `&mut`, or the path to any
+/// other single-parameter `Deref` wrapper. Each gets a forwarding impl of the
+/// ranked trait at every rank, and of the original trait. Forwarded methods
+/// must take `&self`, or `&mut self` for a wrapper that allows it (not `Rc`,
+/// `Arc` or `&`; another wrapper must implement `DerefMut`).
+///
+///
+/// ### Default methods
+/// A default method of a `#[decycle]` trait takes part in the cycle when it
+/// states its dependency in its where-clause, like an impl would
+/// (`fn step(&self) -> usize where B: Cb { .. }`). Each impl in the module
+/// that doesn't override it gets a copy, with the bound moved onto the impl,
+/// so the default's calls are ranked like the impl's own.
+///
+/// A bound on a method's own generics (`fn visit<V: Ca>(&self, v: V) where
+/// Wrap<V>: Cb`) is ranked too, at whatever rank the caller proves it: calls
+/// through it stay on the chain, and a `child.visit(v)` passes the same `v` on.
+/// Called from outside the module, such a method runs its body with the bounds
+/// as written.
+///
+/// Any other cyclic where-bound of a method is an edge of its whole impl, like a
+/// default's. It may not name the impl's generics (`where Self: Cb` in `impl<T>
+/// Ca for W<T>`): the impl would then only exist where the bound holds.
+///
+///
+/// ### Helpers
+/// An inherent impl or a free function in the module whose bounds name a
+/// `#[decycle]` trait (`impl Sum { fn total(&self) -> i64 where Term: Evaluate
+/// { .. } }`, `fn twice<E: Evaluate>(e: &E) -> i64`) gets a ranked copy, which
+/// the impls' bodies call instead: helpers don't reset the rank. Called from
+/// outside the module, the helper runs as written. A helper method that shares
+/// its name with a method of a `#[decycle]` trait is not routed.
+///
+///
+/// ### Trait objects
+/// `dyn Node` in the module always names the original trait object, so `impl
+/// Weigh for dyn Node` and a `dyn Node: Weigh` bound take part in a cycle like
+/// any other type. A virtual call restarts the rank at the Final impl, though;
+/// `#[decycle(dyn)]` on the trait adds hidden vtable entries that carry it
+/// instead, and a cycle body's `c.eval()` goes through them when `c` is
+/// declared as an object (`c: &Box<dyn Node>`, `let c: &dyn Node = ..`, an
+/// `as &dyn Node` cast, or `self` in an impl for `dyn Node`); any other
+/// receiver's call is left alone. Only non-generic `&self`/`&mut self` methods
+/// get an entry. An impl
+/// written outside the module takes the entry's default, which calls the
+/// method at full height.
+///
+///
+/// ### Associated consts
+/// A const whose value reads another member's (`const DEPTH: usize = <Term as
+/// Node>::DEPTH + 1;`, `T::DEPTH` under `T: Node`) is evaluated one rank down,
+/// like a method call, and a default naming a `#[decycle]` trait is copied into
+/// each impl. There is no run-time re-entry for consts: in either mode, one
+/// evaluated at the floor fails to compile with "decycle: cycle limit reached",
+/// so const recursion gets `recurse_level` ranks.
+///
+///
+/// ### Type aliases
+/// A `type` alias declared in the module is expanded before the cycle is
+/// analysed, so `impl Cb for Of` and a `where E: Cb` bound name the same type
+/// as the aliased `Expr<Span>`. A generic alias takes its arguments (or its
+/// defaults); aliases from outside the module are opaque, as before. A generic
+/// parameter of the impl or method shadows an alias of the same name.
+///
+///
+/// ### Impls in `const _` blocks
+/// An impl of a `#[decycle]` trait inside a `const _: () = { .. };` block in the
+/// module (as derive and attribute macros emit them) is moved out of the block
+/// and ranked like the others. The block's `use` and `extern crate` items (and
+/// those of any block around it) go with it, so it names what it did; any other
+/// item declared in the block stays there, out of its reach: naming one is an
+/// error. A qualified trait
+/// path moves only with `#[decycle(ranked)]`.
+///
+///
+/// ### `cfg`
+/// A `#[cfg(..)]` on a `#[decycle]` trait, an impl or one of their members is
+/// repeated on everything decycle generates from it, so a feature-gated impl or
+/// method goes away cleanly. `#[cfg_attr(pred, decycle)]` works too: the module
+/// is expanded once for each combination of values of the `cfg` options its
+/// markers depend on, each copy under its own `cfg`s. The markers of one module
+/// may depend on three options at most (`pred` and `not(pred)` both count as
+/// `pred`; `all()` and `any()` as none), which keeps that to eight copies; a
+/// fourth option is an error.
+///
+///
+/// ### Other attribute macros
+/// rustc expands `#[decycle]` on the module before the attribute macros inside
+/// it, which would otherwise run on each copy decycle generates, not once on
+/// the item you wrote. Mark a trait or an impl whose macros must come first
+/// with `#[decycle(expand_first)]` (`#[decycle(dyn, expand_first)]` on a
+/// trait):
+///
+/// ```rust,ignore
+/// #[decycle(expand_first)]
+/// #[async_trait]
+/// impl Ca for A
+/// where
+///     B: Cb,
+/// {
+///     async fn ca(&self, n: usize) -> usize {
+///         B.cb(n).await + 1
+///     }
+/// }
+/// ```
+///
+/// The item's macros run inside the module, next to its `use`s, and decycle
+/// ranks what they emit. Items they emit beside it are left as they are. A
+/// macro must keep the attributes that follow it on the item, as attribute
+/// macros usually do.
+///
+///
+/// ### Polymorphic recursion
+/// An impl whose cyclic bound names a bigger type than its own (`impl<T> Ca for
+/// Nest<T> where Nest<Vec<T>>: Ca`) reaches a new type at every step, so its
+/// floor in unbounded mode has no re-entry to find and panics. Mark it
+/// `#[decycle(erase)]` and implement [`Erase`] for the self type: the floor
+/// erases `self` to one type that implements the trait too, and carries on
+/// through it.
+///
+/// ```rust,ignore
+/// #[decycle(erase)]
+/// impl<T: Size + Clone + 'static> Ca for Nest<T>
+/// where
+///     Nest<Vec<T>>: Ca,
+/// {
+///     fn ca(&self, n: usize) -> usize {
+///         Ca::ca(&Nest(vec![self.0.clone()]), n - 1) + 1
+///     }
+/// }
+///
+/// impl<T: Size + Clone + 'static> decycle::Erase for Nest<T> {
+///     type Erased = Nest<Rc<dyn Size>>;
+///
+///     fn erase(&self) -> Self::Erased {
+///         Nest(Rc::new(self.0.clone()))
+///     }
+/// }
+/// ```
+///
+/// Only `&self` and `self` methods without generics of their own, and with no
+/// other `Self` in the signature, can be erased: any other method on the impl
+/// is a compile error. Bounded mode keeps its limit.
+/// Call the trait as `Ca::ca(&x, ..)` in such a body: method-call syntax on an
+/// ever-growing type overflows rustc's method probe (E0275).
+///
+/// A generic method is re-entered per instantiation, which a parser whose
+/// stream type grows at every step (`Dup<S>`) never repeats. Erase its type
+/// params on the trait method instead:
+///
+/// ```rust,ignore
+/// #[decycle]
+/// pub trait Ca {
+///     #[decycle(erase(S => dyn Stream))]
+///     fn ca<S: Stream>(&self, stream: &mut S, n: usize) -> usize;
+/// }
+/// ```
+///
+/// The signatures stay generic; the floor re-borrows a `&mut S` (`&S`) as
+/// `&mut dyn Stream` (`&dyn Stream`) and re-enters at `S = &mut dyn Stream`,
+/// the one instantiation every floor shares, so `Stream` needs an impl for
+/// `&mut S`. Every type param of the method must be erased, and taken only
+/// behind a reference.
+///
+///
+/// ### Cycles in fn bodies
+/// A `#[decycle]` module can be declared in a fn body, such as a `#[test]` or
+/// an example. A module can't name the items of the fn it's in, so its
+/// `#[decycle]` traits are declared inside it (or outside the fn, and
+/// `#[decycle] use`d by path):
+///
+/// ```rust
+/// # use decycle::decycle;
+/// #[decycle]
+/// mod parity {
+///     #[decycle]
+///     pub trait Even {
+///         fn even(&self, n: u32) -> bool;
+///     }
+///
+///     #[decycle]
+///     pub trait Odd {
+///         fn odd(&self, n: u32) -> bool;
+///     }
+///
+///     pub struct P;
+///
+///     impl Even for P
+///     where
+///         P: Odd,
+///     {
+///         fn even(&self, n: u32) -> bool {
+///             n == 0 || self.odd(n - 1)
+///         }
+///     }
+///
+///     impl Odd for P
+///     where
+///         P: Even,
+///     {
+///         fn odd(&self, n: u32) -> bool {
+///             n != 0 && self.even(n - 1)
+///         }
+///     }
+/// }
+///
+/// use parity::Even;
+/// assert!(parity::P.even(100));
+/// ```
+///
+/// For the same reason, a `#[decycle]` trait declared in a fn body can't take
+/// part in a cycle; a doc example that declares one next to its module needs
+/// its own `fn main`, like the first example. Nor can a block stand in for the
+/// module: inside one, the original trait stays in scope next to its ranked
+/// copy, and every method call on the cycle becomes ambiguous (E0034).
+///
+/// `#[decycle(local)]` on a trait in a fn body keeps its carrier, the
+/// `macro_rules!` that hands the trait to a module, in the block instead of
+/// exporting it from the crate root. The same trait can then be declared in
+/// several fns, such as two `#[test]`s, which otherwise fails with E0428.
+///
+///
+/// ### Bounds on the trait
+/// A cyclic bound can be declared once, on the trait, instead of on every
+/// impl: a generic parameter bounded by another `#[decycle]` trait (`trait
+/// Visit<T: Cb>`), or a where-clause naming one.
+///
+/// ```rust,ignore
+/// #[decycle]
+/// pub trait Ca
+/// where
+///     Child<Self>: Cb,
+/// {
+///     fn ca(&self, n: usize) -> usize;
+/// }
+///
+/// impl Ca for A {
+///     fn ca(&self, n: usize) -> usize {
+///         Child(A).cb(n - 1) + 1
+///     }
+/// }
+/// ```
+///
+/// Each impl takes them as its own where-bounds (`Child<A>: Cb` here), and
+/// they are ranked like the ones it spells out. A default method of such a
+/// trait is copied into the impls that don't override it.
+///
+/// An associated type bounded by a `#[decycle]` trait is an edge too: with
+/// `type Child: Cb;` on the trait, `impl Ca for A { type Child = B; }` takes
+/// `B: Cb` as its where-bound, so a body can call `self.child().cb(..)`. A
+/// generic impl that only has `T: Ca` names the edge itself (`<T as
+/// Ca>::Child: Cb`). A generic associated type's bound (`type Child<T>: Cb;`)
+/// can't be an edge, as it holds for every `T`: it names the original trait,
+/// which the module shadows. A call through it only resolves by the trait's
+/// full path (`crate::m::Cb::cb(&child, n)`), and restarts the rank count at
+/// `recurse_level`, like a call from outside the module.
+///
+///
+/// ### Attributes
+/// An impl's method is compiled as several copies: the inductive copy holding
+/// your body, the Final method callers outside the module see, and the floor.
+/// Its attributes go where they mean the same thing as on the original:
+///
+/// | attribute                      | body | Final | floor |
+/// |--------------------------------|------|-------|-------|
+/// | `cfg`, `track_caller`          | yes  | yes   | yes   |
+/// | `inline`, `cold`, `doc`        | yes  | yes   |       |
+/// | `must_use`, `deprecated`       |      | yes   |       |
+/// | lint levels, anything else     | yes  |       |       |
+///
+/// So a lint in a body fires once (except `unused_variables`, which decycle
+/// allows there), and a `#[track_caller]` location is the
+/// caller's call site, through `dyn` entries and forwarding impls too — except
+/// past the floor in unbounded mode, where re-entry goes through a fn pointer
+/// and the location is decycle's, and for an `extern "C"` (non-Rust ABI)
+/// method, which can't track its caller. An attribute on the impl itself goes on the
+/// inductive copy and the Final impl (an `expect` only where your code is).
+/// Calls decycle generates never trip a `#[deprecated]` trait method.
+/// A `#[must_use]` trait method warns on a call in a cycle body under the
+/// ranked trait's name, with a message naming the original `Trait::method`.
 ///
 ///
 /// ### Recursion limits
@@ -171,7 +516,8 @@ pub use decycle_impl::process_trait;
 /// directly and calls [`finalize::finalize`], bypassing the token-carrier ping-pong entirely
 /// (and its `crate_version` assertion, which only guards the `Parse` carrier path). This
 /// surface is **semver-committed**: `FinalizeArgs`' fields and `finalize`'s signature are part
-/// of the public API.
+/// of the public API. Build it with `..Default::default()` for the fields you don't set, so a
+/// field added later doesn't break the literal.
 ///
 /// (The macro ping-pong protocol used by `#[decycle]` itself also delegates here, `Parse`ing
 /// `FinalizeArgs` from the specific token shape the generated carrier macros feed back into
@@ -273,12 +619,39 @@ pub mod __reentry {
              key had no same-instantiation frame run on this thread's descent first — e.g. a \
              generic method's first descent at cycle width > recurse_level (including \
              self-recursion consuming ranks before the first generic cross-edge call), or an \
-             impl whose cyclic bound targets a bare type parameter. Increase recurse_level; \
-             if two same-signature closures are involved, give them distinct named types.",
+             impl whose cyclic bound targets a bare type parameter. Increase recurse_level, or \
+             erase the method's type parameters (`#[decycle(erase(S => dyn Trait))]`); if two \
+             same-signature closures are involved, give them distinct named types.",
         )
     }
 }
 
+/// How the floor of a `#[decycle(erase)]` impl re-enters its cycle: every instantiation of the
+/// impl's self type erases to the one type `Erased`, whose own ranks are compiled once (see
+/// "Polymorphic recursion" in the [`decycle`] docs).
+pub trait Erase {
+    /// The type every instantiation erases to, e.g. `Nest<Rc<dyn Size>>` for `Nest<T>`. It
+    /// implements the trait itself, with the same arguments.
+    type Erased;
+
+    fn erase(&self) -> Self::Erased;
+}
+
+/// The depth of a rank (`()` is 0, `(R,)` is one more than `R`), which a ranked body passes
+/// to a `#[decycle(dyn)]` trait's vtable entry to continue at its own rank.
+#[doc(hidden)]
+pub trait __RankDepth {
+    const DEPTH: usize;
+}
+
+impl __RankDepth for () {
+    const DEPTH: usize = 0;
+}
+
+impl<R: __RankDepth> __RankDepth for (R,) {
+    const DEPTH: usize = R::DEPTH + 1;
+}
+
 #[doc(hidden)]
 pub use decycle_impl::proc_macro_error;
 #[cfg(feature = "type-leak")]
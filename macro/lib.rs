@@ -16,6 +16,7 @@ struct Args {
     allowed_paths: Option<Vec<Path>>,
     recurse_level: Option<usize>,
     support_infinite_cycle: Option<bool>,
+    local: Option<Span>,
 }
 
 impl Parse for Args {
@@ -27,6 +28,7 @@ impl Parse for Args {
             allowed_paths: None,
             recurse_level: None,
             support_infinite_cycle: None,
+            local: None,
         };
         syn::custom_keyword!(decycle);
         syn::custom_keyword!(marker);
@@ -34,6 +36,7 @@ impl Parse for Args {
         syn::custom_keyword!(allowed_paths);
         syn::custom_keyword!(recurse_level);
         syn::custom_keyword!(support_infinite_cycle);
+        syn::custom_keyword!(local);
         while !input.is_empty() {
             let lookahead = input.lookahead1();
             if lookahead.peek(decycle) {
@@ -65,10 +68,12 @@ impl Parse for Args {
                 input.parse::<Token![=]>()?;
                 let lit: LitBool = input.parse()?;
                 args.support_infinite_cycle = Some(lit.value);
+            } else if lookahead.peek(local) {
+                args.local = Some(input.parse::<local>()?.span.unwrap());
             } else {
                 abort!(
                     input.span(),
-                    "keyword arguments should be one of 'decycle', 'marker', 'alter_macro_name', 'allowed_paths', 'recurse_level', 'support_infinite_cycle'"
+                    "keyword arguments should be one of 'decycle', 'marker', 'alter_macro_name', 'allowed_paths', 'recurse_level', 'support_infinite_cycle', 'local'"
                 )
             }
             if input.parse::<Token![,]>().is_err() {
@@ -110,6 +115,13 @@ pub fn decycle(attr: TokenStream, input: TokenStream) -> TokenStream {
                 "allowed_paths is not supported for modules"
             )
         }
+        if let Some(local) = args.local {
+            abort!(
+                local,
+                "local is not supported for modules";
+                hint = "a module in a fn body needs no argument"
+            )
+        }
         ret.into()
     } else if let Ok(item) = parse::<ItemTrait>(input.clone()) {
         let mut config = type_leak::LeakerConfig::new();
@@ -124,6 +136,7 @@ pub fn decycle(attr: TokenStream, input: TokenStream) -> TokenStream {
             &decycle_path,
             args.marker.as_ref(),
             args.alter_macro_name.as_ref(),
+            args.local.is_some(),
             config,
         );
         set_dummy(quote!(#ret));
@@ -170,3 +183,12 @@ pub fn __finalize(input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(input as decycle_impl::finalize::FinalizeArgs);
     decycle_impl::finalize::finalize(args).into()
 }
+
+#[doc(hidden)]
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn __expand_first(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as decycle_impl::ExpandFirstArgs);
+    let item = parse_macro_input!(input as Item);
+    decycle_impl::resume_expand_first(args, item).into()
+}
@@ -87,14 +87,12 @@ pub fn also_rank_projection_demo(_input: TokenStream) -> TokenStream {
     };
 
     let args = FinalizeArgs {
-        working_list: Vec::new(),
         traits: vec![ca_trait.clone(), cb_trait.clone()],
         contents: vec![ca_impl, cb_impl],
         recurse_level: 1,
-        support_infinite_cycle: true,
-        renames: Vec::new(),
         also_rank: vec![also_rank],
         decycle_path: Some(parse_quote!(::decycle)),
+        ..Default::default()
     };
 
     let generated = finalize(args);
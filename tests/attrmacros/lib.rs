@@ -0,0 +1,80 @@
+//! Test-only attribute macros for `#[decycle(expand_first)]`, standing in for the kind that
+//! run on impls inside a `#[decycle]` module: `async_box` rewrites signatures and bodies the
+//! way `async_trait` does, and `count_calls` instruments bodies and emits an item beside the
+//! impl, like `#[instrument]`-style macros.
+
+use proc_macro::TokenStream;
+use syn::*;
+use template_quote::quote;
+
+/// On a trait or an impl: each `async fn f(..) -> T` becomes a `fn f(..)` returning
+/// `Pin<Box<dyn Future<Output = T> + '_>>`, its body (if any) boxed into an `async move` block.
+#[proc_macro_attribute]
+pub fn async_box(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    fn rewrite(sig: &mut Signature, block: Option<&mut Block>) {
+        if sig.asyncness.take().is_none() {
+            return;
+        }
+        let output = match &sig.output {
+            ReturnType::Default => quote!(()),
+            ReturnType::Type(_, ty) => quote!(#ty),
+        };
+        sig.output = parse_quote! {
+            -> ::core::pin::Pin<::std::boxed::Box<
+                dyn ::core::future::Future<Output = #output> + '_
+            >>
+        };
+        if let Some(block) = block {
+            let body = block.clone();
+            *block = parse_quote!({ ::std::boxed::Box::pin(async move #body) });
+        }
+    }
+    let mut item: Item = parse_macro_input!(input);
+    match &mut item {
+        Item::Trait(item_trait) => {
+            for item in &mut item_trait.items {
+                if let TraitItem::Fn(f) = item {
+                    rewrite(&mut f.sig, f.default.as_mut());
+                }
+            }
+        }
+        Item::Impl(item_impl) => {
+            for item in &mut item_impl.items {
+                if let ImplItem::Fn(f) = item {
+                    rewrite(&mut f.sig, Some(&mut f.block));
+                }
+            }
+        }
+        _ => panic!("#[async_box] goes on a trait or an impl"),
+    }
+    quote!(#item).into()
+}
+
+/// On an impl: each method body first bumps the `CALLS` static in scope, and the impl gets a
+/// `const` beside it naming its self type.
+#[proc_macro_attribute]
+pub fn count_calls(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    let mut item_impl: ItemImpl = parse_macro_input!(input);
+    for item in &mut item_impl.items {
+        if let ImplItem::Fn(f) = item {
+            f.block.stmts.insert(
+                0,
+                parse_quote!(CALLS.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);),
+            );
+        }
+    }
+    let Type::Path(self_ty) = &*item_impl.self_ty else {
+        panic!("#[count_calls] needs a named self type");
+    };
+    let name = Ident::new(
+        &format!("COUNTED_{}", self_ty.path.segments.last().unwrap().ident),
+        proc_macro2::Span::call_site(),
+    );
+    let self_name = quote!(#self_ty).to_string();
+    quote! {
+        #item_impl
+        #[allow(dead_code)]
+        pub const #name: &str = #self_name;
+    }
+    .into()
+}
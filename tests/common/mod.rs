@@ -0,0 +1,9 @@
+//! Helpers shared by the integration tests.
+
+/// The message of a caught panic, whichever of `String` or `&str` it carries.
+pub fn panic_msg(e: Box<dyn std::any::Any + Send>) -> String {
+    e.downcast_ref::<String>()
+        .cloned()
+        .or_else(|| e.downcast_ref::<&str>().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
@@ -9,9 +9,14 @@
 //! F-C1's side-bound reachability check, exercised at width 6 with heavily divergent
 //! `Send`/`Sync`/`Debug`/`Default`/`PartialEq`/`Clone` side-bounds across the impls, that makes
 //! it compile at all in default (unbounded) mode.
+//!
+//! The cycle edges that don't come from an impl's own where-clause follow, one wrapper mod
+//! each.
 
 use decycle::decycle;
 
+mod common;
+
 #[decycle]
 trait Compute<T> {
     type Output;
@@ -134,6 +139,9 @@ mod validation_types {
     }
 }
 
+// The `Foreign: super::Trait` side-bounds below deliberately name the ORIGINAL, un-ranked
+// traits (their bounded types aren't part of the cycle); `#[decycle(unranked)]` says so.
+//
 // Shared macro for the two mode variants (default/unbounded and `support_infinite_cycle =
 // false`/bounded) — only the module's own `#[decycle(...)]` attribute and module name differ,
 // so the dense cycle body is written once.
@@ -152,6 +160,7 @@ macro_rules! dense_cycle_module {
             pub struct ValidatorX<T>(pub T);
             pub struct ValidatorY<T>(pub T);
 
+            #[decycle(unranked)]
             impl<T> Compute<T> for ProcessorA<T>
             where
                 T: Clone + Debug,
@@ -167,6 +176,7 @@ macro_rules! dense_cycle_module {
                 }
             }
 
+            #[decycle(unranked)]
             impl<T> Process for ProcessorA<T>
             where
                 T: Debug + Send + Sync,
@@ -178,6 +188,7 @@ macro_rules! dense_cycle_module {
                 fn process(&self) {}
             }
 
+            #[decycle(unranked)]
             impl<T> Process for ProcessorB<T>
             where
                 T: Clone + PartialEq,
@@ -189,6 +200,7 @@ macro_rules! dense_cycle_module {
                 fn process(&self) {}
             }
 
+            #[decycle(unranked)]
             impl<T> Validate<T> for ProcessorB<T>
             where
                 T: Debug + Clone + Default,
@@ -201,6 +213,7 @@ macro_rules! dense_cycle_module {
                 }
             }
 
+            #[decycle(unranked)]
             impl<T> Compute<T> for ProcessorC<T>
             where
                 T: Clone + Send + 'static,
@@ -318,3 +331,772 @@ fn dense_cycle_default_mode() {
 fn dense_cycle_bounded_mode() {
     dense_cycle_test_body!(complex_bounded);
 }
+
+/// Blanket impls as cycle participants. `impl<T: Leaf> Ca for T where B: Cb` covers every
+/// `Leaf` type, next to a concrete `impl Ca for C` (`C` isn't `Leaf`); `impl<T: Cb> Cz for T`
+/// is bounded only by another cycle trait. Both get a full ranked chain, and their
+/// registrations let the unbounded mode descend past `recurse_level`. Run in both modes.
+#[allow(dead_code)]
+mod blanket_impls {
+    use decycle::decycle;
+
+    macro_rules! blanket_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cz {
+                    fn cz(&self, n: usize) -> usize;
+                }
+
+                pub trait Leaf {
+                    fn id(&self) -> usize;
+                }
+
+                pub struct A;
+                pub struct B;
+                pub struct C;
+
+                impl Leaf for A {
+                    fn id(&self) -> usize {
+                        1
+                    }
+                }
+
+                impl Leaf for B {
+                    fn id(&self) -> usize {
+                        2
+                    }
+                }
+
+                impl<T: Leaf> Ca for T
+                where
+                    B: Cb,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            self.id()
+                        } else {
+                            B.cb(n - 1) + 1
+                        }
+                    }
+                }
+
+                impl Ca for C
+                where
+                    A: Ca,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        A.ca(n) + 100
+                    }
+                }
+
+                impl Cb for B
+                where
+                    A: Ca,
+                    A: Cz,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n % 2 == 0 {
+                            A.ca(n) + 1
+                        } else {
+                            A.cz(n) + 1
+                        }
+                    }
+                }
+
+                impl Cb for A
+                where
+                    B: Ca,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        B.ca(n)
+                    }
+                }
+
+                impl<T: Cb> Cz for T {
+                    fn cz(&self, n: usize) -> usize {
+                        self.cb(n)
+                    }
+                }
+            }
+        };
+    }
+
+    blanket_module!(decycle(recurse_level = 2), unbounded);
+    blanket_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn blanket_impls_unbounded() {
+        use unbounded::{Ca, Cz};
+        assert_eq!(unbounded::A.ca(0), 1);
+        assert_eq!(unbounded::B.ca(0), 2);
+        assert_eq!(unbounded::A.ca(40), 81);
+        assert_eq!(unbounded::C.ca(40), 181);
+        assert_eq!(unbounded::B.cz(7), 16);
+    }
+
+    #[test]
+    fn blanket_impls_bounded() {
+        use bounded::{Ca, Cz};
+        assert_eq!(bounded::A.ca(2), 5);
+        assert_eq!(bounded::C.ca(1), 103);
+        assert_eq!(bounded::A.cz(1), 3);
+    }
+}
+
+/// Default methods of #[decycle] traits that state a cyclic dependency in their where-clause
+/// (`fn step(&self, n: usize) -> usize where B: Cb<u8>`). Each impl that doesn't override one
+/// gets it copied in, with the bound moved onto the impl, so the default's calls count the rank
+/// down like an impl-provided method's instead of re-entering through the Final impl. `Cb<K>`'s
+/// default is substituted with the impl's `K = u8`, and `Ca<W>`'s with its `W = 1`.
+/// `hop` states no bound at all: a default calling into the cycle is copied the same way, and
+/// ranked against the impl's bounds. Run in both modes.
+#[allow(dead_code)]
+mod default_methods {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! default_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Ca<const W: usize> {
+                    fn ca(&self, n: usize) -> usize;
+
+                    fn hop(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            B.cb(n - 1, 2) * W + 1
+                        }
+                    }
+
+                    fn step(&self, n: usize) -> usize
+                    where
+                        B: Cb<u8>,
+                    {
+                        if n == 0 {
+                            0
+                        } else {
+                            B.cb(n - 1, 1) + W
+                        }
+                    }
+                }
+
+                #[decycle]
+                pub trait Cb<K: Copy + Into<usize>> {
+                    fn cb(&self, n: usize, k: K) -> usize;
+
+                    fn back(&self, n: usize, k: K) -> usize
+                    where
+                        A: Ca<1>,
+                    {
+                        let k: usize = k.into();
+                        if n == 0 {
+                            k
+                        } else {
+                            A.ca(n - 1) + k
+                        }
+                    }
+                }
+
+                pub struct A;
+                pub struct B;
+
+                impl Ca<1> for A {
+                    fn ca(&self, n: usize) -> usize {
+                        self.step(n)
+                    }
+                }
+
+                impl Cb<u8> for B {
+                    fn cb(&self, n: usize, k: u8) -> usize {
+                        self.back(n, k)
+                    }
+                }
+            }
+        };
+    }
+
+    default_module!(decycle(recurse_level = 2), unbounded);
+    default_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn default_methods_unbounded() {
+        use unbounded::{Ca, Cb};
+        assert_eq!(unbounded::A.ca(0), 0);
+        assert_eq!(unbounded::A.ca(40), 40);
+        assert_eq!(unbounded::A.ca(41), 42);
+        assert_eq!(unbounded::B.back(3, 5), 7);
+        assert_eq!(unbounded::A.hop(1), 3);
+        assert_eq!(unbounded::A.hop(41), 43);
+    }
+
+    #[test]
+    fn default_methods_bounded() {
+        use bounded::{Ca, Cb};
+        assert_eq!(bounded::A.ca(2), 2);
+        assert_eq!(bounded::B.back(1, 5), 5);
+        assert_eq!(bounded::A.hop(1), 3);
+        // The defaults are ranked: past `recurse_level` they hit the leaf instead of recursing
+        // through the Final impls forever.
+        let e = std::panic::catch_unwind(|| bounded::A.ca(50)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// Cyclic bounds on a method's own generics (`fn visit<V: Ca>(..) where Wrap<V>: Cb`): the
+/// ranked trait declares them ranked, so a call through them inside a cycle body stays on the
+/// rank chain instead of resetting through the Final impls. Entered from outside, `visit` runs
+/// the user's body with the bounds un-ranked. `visit_twice` is a default with such a bound,
+/// copied into the impl like any default with a cyclic bound. `parse` shares its name with
+/// `str::parse`, which its body calls with a turbofish: only the call on a `Cv` gets the hidden
+/// rank argument. Run in both modes.
+#[allow(dead_code)]
+mod method_bounds {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! method_bounds_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cv {
+                    fn visit<V: Ca>(&self, v: V, n: usize) -> usize
+                    where
+                        Wrap<V>: Cb;
+
+                    fn visit_twice<V: Ca + Copy>(&self, v: V, n: usize) -> usize
+                    where
+                        Wrap<V>: Cb,
+                    {
+                        self.visit::<V>(v, n) + Wrap(v).cb(n)
+                    }
+
+                    fn parse<V: Ca>(&self, v: V, n: usize) -> usize
+                    where
+                        Wrap<V>: Cb;
+                }
+
+                #[derive(Clone, Copy)]
+                pub struct A;
+                pub struct X;
+                pub struct Wrap<V>(pub V);
+
+                impl Ca for A
+                where
+                    X: Cv,
+                    A: Ca,
+                    Wrap<A>: Cb,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            X.visit::<A>(A, n - 1) + 1
+                        }
+                    }
+                }
+
+                impl<V: Ca> Cb for Wrap<V> {
+                    fn cb(&self, n: usize) -> usize {
+                        self.0.ca(n) + 10
+                    }
+                }
+
+                impl Cv for X {
+                    fn visit<V: Ca>(&self, v: V, n: usize) -> usize
+                    where
+                        Wrap<V>: Cb,
+                    {
+                        Wrap(v).cb(n)
+                    }
+
+                    fn parse<V: Ca>(&self, v: V, n: usize) -> usize
+                    where
+                        Wrap<V>: Cb,
+                    {
+                        self.visit::<V>(v, n) + "0".parse::<usize>().unwrap()
+                    }
+                }
+            }
+        };
+    }
+
+    method_bounds_module!(decycle(recurse_level = 2), unbounded);
+    method_bounds_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn method_bounds_unbounded() {
+        use unbounded::{Ca, Cv};
+        assert_eq!(unbounded::A.ca(0), 0);
+        assert_eq!(unbounded::A.ca(30), 330);
+        assert_eq!(unbounded::X.visit(unbounded::A, 30), 340);
+        assert_eq!(unbounded::X.visit_twice(unbounded::A, 30), 680);
+        assert_eq!(unbounded::X.parse(unbounded::A, 30), 340);
+    }
+
+    #[test]
+    fn method_bounds_bounded() {
+        use bounded::{Ca, Cv};
+        assert_eq!(bounded::A.ca(1), 11);
+        assert_eq!(bounded::X.visit(bounded::A, 1), 21);
+        assert_eq!(bounded::X.visit_twice(bounded::A, 1), 42);
+        assert_eq!(bounded::X.parse(bounded::A, 1), 21);
+        // `visit`'s calls count the rank down: past `recurse_level` they hit a floor instead of
+        // recursing through the Final impls forever.
+        let e = std::panic::catch_unwind(|| bounded::A.ca(50)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// An inherent method (`Sum::total`) and a free fn (`twice`) with cyclic bounds, called from
+/// the cycle bodies: their ranked copies keep the calls on the rank chain instead of resetting
+/// it through the Final impls. Called from outside, they run as written. `Sum::len` and
+/// `collect` share their names with `Vec::len` and `Iterator::collect`, which the same bodies
+/// call: only the calls on a `Sum` and the bare `collect(..)` go to the ranked copies. Run in
+/// both modes.
+#[allow(dead_code)]
+mod helper_fns {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! helper_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Evaluate {
+                    fn evaluate(&self) -> i64;
+                }
+
+                pub struct Sum(pub Vec<Term>);
+                pub struct Term(pub i64, pub Option<Box<Sum>>);
+
+                impl Evaluate for Sum
+                where
+                    Term: Evaluate,
+                {
+                    fn evaluate(&self) -> i64 {
+                        self.total()
+                    }
+                }
+
+                impl Sum {
+                    pub fn total(&self) -> i64
+                    where
+                        Term: Evaluate,
+                    {
+                        let terms: Vec<i64> = collect(&self.0);
+                        assert_eq!(terms.len(), self.len());
+                        terms.into_iter().sum()
+                    }
+
+                    pub fn len(&self) -> usize
+                    where
+                        Term: Evaluate,
+                    {
+                        self.0.len()
+                    }
+                }
+
+                pub fn collect(terms: &[Term]) -> Vec<i64>
+                where
+                    Term: Evaluate,
+                {
+                    terms.iter().map(|t| t.evaluate()).collect::<Vec<i64>>()
+                }
+
+                impl Evaluate for Term
+                where
+                    Sum: Evaluate,
+                {
+                    fn evaluate(&self) -> i64 {
+                        self.0 + twice::<Sum>(self.1.as_deref())
+                    }
+                }
+
+                pub fn twice<E: Evaluate>(e: Option<&E>) -> i64 {
+                    e.map_or(0, |e| 2 * e.evaluate())
+                }
+            }
+        };
+    }
+
+    helper_module!(decycle(recurse_level = 2), unbounded);
+    helper_module!(decycle(support_infinite_cycle = false), bounded);
+
+    macro_rules! nested {
+        ($m:ident, $depth:expr) => {
+            (0..$depth).fold($m::Sum(vec![$m::Term(1, None)]), |s, _| {
+                $m::Sum(vec![$m::Term(1, Some(Box::new(s))), $m::Term(2, None)])
+            })
+        };
+    }
+
+    #[test]
+    fn helper_fns_unbounded() {
+        use unbounded::Evaluate;
+        assert_eq!(nested!(unbounded, 0).evaluate(), 1);
+        assert_eq!(nested!(unbounded, 1).evaluate(), 5);
+        assert_eq!(nested!(unbounded, 20).total(), (1 << 22) - 3);
+        assert_eq!(unbounded::twice(Some(&unbounded::Term(4, None))), 8);
+        assert_eq!(nested!(unbounded, 1).len(), 2);
+        assert_eq!(unbounded::collect(&nested!(unbounded, 1).0), vec![3, 2]);
+    }
+
+    #[test]
+    fn helper_fns_bounded() {
+        use bounded::Evaluate;
+        assert_eq!(nested!(bounded, 0).evaluate(), 1);
+        assert_eq!(nested!(bounded, 1).total(), 5);
+        assert_eq!(bounded::twice(Some(&bounded::Term(4, None))), 8);
+        assert_eq!(bounded::collect(&nested!(bounded, 1).0), vec![3, 2]);
+        // Through the helpers, each level still counts the rank down.
+        let e = std::panic::catch_unwind(|| nested!(bounded, 20).evaluate()).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// Associated consts in a cycle: `<Term as Node>::DEPTH` in a const's value is read one rank
+/// down, like a method call, so mutually recursive node types can compute compile-time
+/// metadata from each other. A default naming a `#[decycle]` trait is copied into each impl
+/// and ranked there. Consts have no run-time re-entry, so they get `recurse_level` ranks in
+/// either mode (`tests/ui/const_cycle.rs` is the floor). Run in both modes.
+#[allow(dead_code)]
+mod assoc_consts {
+    use decycle::decycle;
+
+    macro_rules! consts_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Node {
+                    const DEPTH: usize;
+                    const ARITY: usize = 0;
+                    const HEIGHT: usize = <Self as Node>::DEPTH + 1;
+
+                    fn depth(&self) -> usize {
+                        Self::DEPTH
+                    }
+                }
+
+                pub struct Expr;
+                pub struct Term;
+                pub struct Lit;
+                pub struct Wrap<T>(pub T);
+
+                impl Node for Expr
+                where
+                    Term: Node,
+                {
+                    const DEPTH: usize = <Term as Node>::DEPTH + 1;
+                    const ARITY: usize = 2;
+                }
+
+                impl Node for Term
+                where
+                    Lit: Node,
+                    Expr: Node,
+                {
+                    const DEPTH: usize = <Lit as Node>::DEPTH + 1;
+                    const ARITY: usize = <Expr as Node>::ARITY;
+                }
+
+                impl Node for Lit
+                where
+                    Expr: Node,
+                {
+                    const DEPTH: usize = 0;
+                }
+
+                impl<T: Node> Node for Wrap<T> {
+                    const DEPTH: usize = T::DEPTH + 1;
+                    const ARITY: usize = 1;
+                }
+            }
+        };
+    }
+
+    consts_module!(decycle(recurse_level = 4), unbounded);
+    consts_module!(decycle(support_infinite_cycle = false), bounded);
+
+    macro_rules! consts_test {
+        ($name:ident, $m:ident) => {
+            #[test]
+            fn $name() {
+                use $m::{Expr, Lit, Node, Term, Wrap};
+                const EXPR_DEPTH: usize = <Expr as Node>::DEPTH;
+                assert_eq!(EXPR_DEPTH, 2);
+                assert_eq!(<Term as Node>::ARITY, 2);
+                assert_eq!(<Lit as Node>::ARITY, 0);
+                assert_eq!(<Expr as Node>::HEIGHT, 3);
+                assert_eq!(<Wrap<Expr> as Node>::DEPTH, 3);
+                assert_eq!(Wrap(Term).depth(), 2);
+            }
+        };
+    }
+
+    consts_test!(assoc_consts_unbounded, unbounded);
+    consts_test!(assoc_consts_bounded, bounded);
+}
+
+/// Cyclic bounds declared on the trait rather than on its impls: a where-clause naming another
+/// `#[decycle]` trait (`where Child<Self>: Cb`), a generic parameter bounded by one (`trait
+/// Visit<T: Cb>`), and a default method relying on that bound. Run in both modes.
+#[allow(dead_code)]
+mod trait_bounds {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! trait_bounds_module {
+        ($name:ident, $decycle_attr:meta) => {
+            #[$decycle_attr]
+            mod $name {
+                #[decycle]
+                pub trait Ca
+                where
+                    Child<Self>: Cb,
+                    Self: Sized,
+                {
+                    fn ca(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Visit<T: Cb> {
+                    fn visit(&self, t: &T, n: usize) -> usize;
+
+                    fn peek(&self, t: &T) -> usize {
+                        t.cb(1)
+                    }
+                }
+
+                pub struct A;
+                pub struct Child<T>(pub T);
+                pub struct V;
+                pub struct W<T>(pub T);
+
+                // No `where Child<A>: Cb`: the trait's where-clause supplies it.
+                impl Ca for A {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            Child(A).cb(n - 1) + 1
+                        }
+                    }
+                }
+
+                impl Cb for Child<A>
+                where
+                    A: Ca,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            A.ca(n - 1) + 10
+                        }
+                    }
+                }
+
+                impl Cb for V
+                where
+                    V: Visit<V>,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            V.visit(self, n - 1) + 100
+                        }
+                    }
+                }
+
+                // No `where V: Cb`: the trait's `T: Cb` supplies it.
+                impl Visit<V> for V {
+                    fn visit(&self, t: &V, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            t.cb(n - 1) + 1000
+                        }
+                    }
+                }
+
+                impl<T> Visit<T> for W<T> {
+                    fn visit(&self, t: &T, n: usize) -> usize {
+                        t.cb(n) + 5
+                    }
+                }
+            }
+        };
+    }
+
+    trait_bounds_module!(unbounded, decycle(recurse_level = 2));
+    trait_bounds_module!(bounded, decycle(support_infinite_cycle = false));
+
+    #[test]
+    fn trait_bounds_unbounded() {
+        use unbounded::*;
+        assert_eq!(A.ca(1000), 5500);
+        assert_eq!(V.visit(&V, 400), 220000);
+        assert_eq!(V.peek(&V), 100);
+        assert_eq!(W(V).visit(&V, 3), 1205);
+    }
+
+    #[test]
+    fn trait_bounds_bounded() {
+        use bounded::*;
+        assert_eq!(A.ca(4), 22);
+        assert_eq!(V.visit(&V, 4), 2200);
+        assert_eq!(V.peek(&V), 100);
+        assert_eq!(W(V).visit(&V, 3), 1205);
+        let e = std::panic::catch_unwind(|| A.ca(100)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// Cycles through associated types: `type Child: Cb;` on the trait and `type Child = B;` on the
+/// impl make `B: Cb` an edge of that impl, without a where-clause. Covers a concrete child, a
+/// child set to the impl's own type param, a default method calling through the child, and a
+/// generic impl bounding `<T as Ca>::Child` itself. Run in both modes.
+#[allow(dead_code)]
+mod assoc_type_edges {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! assoc_edges_module {
+        ($name:ident, $decycle_attr:meta) => {
+            #[$decycle_attr]
+            mod $name {
+                #[decycle]
+                pub trait Ca {
+                    type Child: Cb;
+
+                    fn child(&self) -> Self::Child;
+
+                    fn ca(&self, n: usize) -> usize;
+
+                    fn via_child(&self, n: usize) -> usize {
+                        self.child().cb(n) + 7
+                    }
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                pub struct A;
+                #[derive(Clone)]
+                pub struct B;
+                pub struct Wrap<T>(pub T);
+                pub struct Up<T>(pub T);
+
+                impl Ca for A {
+                    type Child = B;
+
+                    fn child(&self) -> B {
+                        B
+                    }
+
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            self.child().cb(n - 1) + 1
+                        }
+                    }
+                }
+
+                impl Cb for B
+                where
+                    A: Ca,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            A.ca(n - 1) + 10
+                        }
+                    }
+                }
+
+                impl<T: Clone> Ca for Wrap<T> {
+                    type Child = T;
+
+                    fn child(&self) -> Self::Child {
+                        self.0.clone()
+                    }
+
+                    fn ca(&self, n: usize) -> usize {
+                        self.child().cb(n) + 100
+                    }
+                }
+
+                impl<T> Cb for Up<T>
+                where
+                    T: Ca,
+                    <T as Ca>::Child: Cb,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        self.0.child().cb(n) + 1000
+                    }
+                }
+            }
+        };
+    }
+
+    assoc_edges_module!(unbounded, decycle(recurse_level = 2));
+    assoc_edges_module!(bounded, decycle(support_infinite_cycle = false));
+
+    #[test]
+    fn assoc_type_edges_unbounded() {
+        use unbounded::*;
+        assert_eq!(A.ca(1000), 5500);
+        assert_eq!(A.via_child(3), 28);
+        assert_eq!(Wrap(B).ca(1001), 5610);
+        assert_eq!(Up(A).cb(1001), 6510);
+    }
+
+    #[test]
+    fn assoc_type_edges_bounded() {
+        use bounded::*;
+        assert_eq!(A.ca(4), 22);
+        assert_eq!(A.via_child(3), 28);
+        assert_eq!(Wrap(B).ca(3), 121);
+        assert_eq!(Up(A).cb(3), 1021);
+        let e = std::panic::catch_unwind(|| A.ca(100)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
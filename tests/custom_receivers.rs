@@ -0,0 +1,195 @@
+//! Custom receivers past the floor: `self: Box<Self>` (tree builders consuming themselves),
+//! `self: Rc<Self>` / `Arc<Self>`, and `self: Pin<&mut Self>` (streaming parser states).
+//! The re-entry fn-pointer types spell the receiver as a leading typed param; elision of
+//! the output takes a pinned borrow's lifetime as it would `&self`'s; and a `Box<Self>`
+//! receiver leaves an unsized `Self` unsized.
+
+// The cycle's non-entry traits are only called through their ranked variants after expansion.
+#![allow(dead_code)]
+
+use decycle::decycle;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// ---------------------------------------------------------------------------------------------
+// Owning receivers: each step moves the node into the next one's builder.
+// ---------------------------------------------------------------------------------------------
+
+#[decycle(recurse_level = 2)]
+mod owning_m {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[decycle]
+    pub trait Build {
+        fn build(self: Box<Self>, n: usize) -> usize;
+        fn build_with<T: Copy + Into<usize>>(self: Box<Self>, t: T, n: usize) -> usize;
+        fn share(self: Rc<Self>, n: usize) -> usize;
+        fn send(self: Arc<Self>, n: usize) -> usize;
+    }
+    pub struct A(pub usize);
+    pub struct Wrap<T>(pub T);
+    impl Build for A
+    where
+        Wrap<A>: Build,
+    {
+        fn build(self: Box<Self>, n: usize) -> usize {
+            if n == 0 {
+                self.0
+            } else {
+                Box::new(Wrap(A(self.0))).build(n - 1) + 1
+            }
+        }
+        fn build_with<T: Copy + Into<usize>>(mut self: Box<Self>, t: T, n: usize) -> usize {
+            self.0 += t.into();
+            if n == 0 {
+                self.0
+            } else {
+                Box::new(Wrap(A(self.0))).build_with(t, n - 1)
+            }
+        }
+        fn share(self: Rc<Self>, n: usize) -> usize {
+            if n == 0 {
+                self.0
+            } else {
+                Rc::new(Wrap(A(self.0))).share(n - 1) + 1
+            }
+        }
+        fn send(self: Arc<Self>, n: usize) -> usize {
+            if n == 0 {
+                self.0
+            } else {
+                Arc::new(Wrap(A(self.0))).send(n - 1) + 1
+            }
+        }
+    }
+    impl<T> Build for Wrap<T>
+    where
+        A: Build,
+        T: Into<usize>,
+    {
+        fn build(self: Box<Self>, n: usize) -> usize {
+            Box::new(A(self.0.into())).build(n)
+        }
+        fn build_with<U: Copy + Into<usize>>(self: Box<Self>, t: U, n: usize) -> usize {
+            Box::new(A(self.0.into())).build_with(t, n)
+        }
+        fn share(self: Rc<Self>, n: usize) -> usize {
+            let Ok(this) = Rc::try_unwrap(self) else { unreachable!() };
+            Rc::new(A(this.0.into())).share(n)
+        }
+        fn send(self: Arc<Self>, n: usize) -> usize {
+            let Ok(this) = Arc::try_unwrap(self) else { unreachable!() };
+            Arc::new(A(this.0.into())).send(n)
+        }
+    }
+    impl From<A> for usize {
+        fn from(a: A) -> usize {
+            a.0
+        }
+    }
+}
+
+#[test]
+fn owning_receivers_past_floor() {
+    use owning_m::{Build, A};
+    assert_eq!(Box::new(A(3)).build(2000), 2003);
+    assert_eq!(Box::new(A(0)).build_with(2u8, 2000), 4002);
+    assert_eq!(Box::new(A(0)).build_with(1u16, 2000), 2001);
+    assert_eq!(Rc::new(A(0)).share(2000), 2000);
+    assert_eq!(Arc::new(A(0)).send(2000), 2000);
+}
+
+// ---------------------------------------------------------------------------------------------
+// `Pin<&mut Self>`: the output's elided lifetime is the pinned borrow's, not ambiguous between
+// it and `buf`'s.
+// ---------------------------------------------------------------------------------------------
+
+#[decycle(recurse_level = 2)]
+mod pinned_m {
+    use std::pin::Pin;
+
+    #[decycle]
+    pub trait Step {
+        fn step(self: Pin<&mut Self>, buf: &[u8], n: usize) -> &usize;
+    }
+    pub struct Lhs(pub usize, pub Rhs);
+    pub struct Rhs(pub usize);
+    impl Step for Lhs
+    where
+        Rhs: Step,
+    {
+        fn step(self: Pin<&mut Self>, buf: &[u8], n: usize) -> &usize {
+            let this = self.get_mut();
+            this.0 += buf.len();
+            if n == 0 {
+                &this.0
+            } else {
+                this.1 .0 = this.0;
+                Pin::new(&mut this.1).step(buf, n - 1)
+            }
+        }
+    }
+    impl Step for Rhs
+    where
+        Lhs: Step,
+    {
+        fn step(self: Pin<&mut Self>, buf: &[u8], n: usize) -> &usize {
+            let this = self.get_mut();
+            if n == 0 {
+                &this.0
+            } else {
+                let mut next = Box::new(Lhs(this.0, Rhs(0)));
+                this.0 = *Pin::new(&mut *next).step(buf, n - 1);
+                &this.0
+            }
+        }
+    }
+}
+
+#[test]
+fn pinned_receiver_past_floor() {
+    use pinned_m::{Lhs, Rhs, Step};
+    let mut lhs = Lhs(0, Rhs(0));
+    assert_eq!(*Pin::new(&mut lhs).step(b"ab", 1000), 1002);
+}
+
+// ---------------------------------------------------------------------------------------------
+// An unsized `Self` behind `Box<Self>` keeps the re-entry fn's `Self` param `?Sized`.
+// ---------------------------------------------------------------------------------------------
+
+#[decycle(recurse_level = 2)]
+mod unsized_m {
+    #[decycle]
+    pub trait Len {
+        fn len_of(self: Box<Self>, n: usize) -> usize;
+    }
+    impl Len for str
+    where
+        [u8]: Len,
+    {
+        fn len_of(self: Box<Self>, n: usize) -> usize {
+            if n == 0 {
+                self.len()
+            } else {
+                self.into_boxed_bytes().len_of(n - 1) + 1
+            }
+        }
+    }
+    impl Len for [u8]
+    where
+        str: Len,
+    {
+        fn len_of(self: Box<Self>, n: usize) -> usize {
+            let s = String::from_utf8(self.into_vec()).unwrap();
+            s.into_boxed_str().len_of(n)
+        }
+    }
+}
+
+#[test]
+fn unsized_boxed_receiver_past_floor() {
+    use unsized_m::Len;
+    assert_eq!(Box::<str>::from("abc").len_of(2000), 2003);
+}
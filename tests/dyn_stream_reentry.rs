@@ -3,11 +3,15 @@
 //! Stream + '_)`, syan's `&mut dyn ParseStream` shape) is exactly that — concrete, non-generic,
 //! non-`impl Trait` — so it re-enters unbounded through one fixed `&mut dyn` boundary (no
 //! `Dup<…>`-style stream-type tower growth) instead of tripping the D4 `abort!` (which only
-//! fires for a return-position `impl Trait`, a DIFFERENT shape).
+//! fires for a return-position `impl Trait`, a DIFFERENT shape). A generic method gets the same
+//! boundary at its floor only with `#[decycle(erase(..))]` (`erased_streams.rs`). `forward(..)`
+//! wrappers and `#[decycle(dyn)]` trait objects follow.
 #![allow(dead_code)]
 
 use decycle::decycle;
 
+mod common;
+
 /// A tiny, self-contained stand-in for syan's `dyn ParseStream`: an object-safe trait erasing the
 /// concrete stream type behind a `&mut dyn`.
 pub trait Stream {
@@ -65,3 +69,296 @@ fn dyn_stream_reentry_is_unbounded() {
     let mut s = NullStream;
     assert_eq!(m::A.ca(&mut s, 2000), 2000);
 }
+
+/// `#[decycle(forward(...))]` on a #[decycle] trait: `Box<Expr>`, `Rc<Expr>`, `&Expr` and
+/// `&mut Expr` implement the trait by forwarding to `Expr`, at the ranked level too, so a
+/// `Box<Expr>: Evaluate` bound in a cycle body keeps counting the rank down instead of
+/// re-entering through the Final impl. `Count` has an associated type and a `&mut self`
+/// method. Run in both modes.
+mod forward_wrappers {
+    use decycle::decycle;
+
+    macro_rules! forward_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                use std::rc::Rc;
+
+                #[decycle(forward(Box, Rc, &))]
+                pub trait Evaluate {
+                    fn evaluate(&self) -> i64;
+                }
+
+                #[decycle(forward(Box, &mut))]
+                pub trait Count {
+                    type Out;
+                    fn count(&mut self) -> Self::Out;
+                }
+
+                pub enum Expr {
+                    Lit(i64),
+                    Add(Add),
+                    Neg(Neg),
+                }
+
+                pub struct Add(pub Box<Expr>, pub Box<Expr>);
+                pub struct Neg(pub Rc<Expr>);
+
+                impl Evaluate for Expr
+                where
+                    Add: Evaluate,
+                    Neg: Evaluate,
+                {
+                    fn evaluate(&self) -> i64 {
+                        match self {
+                            Expr::Lit(n) => *n,
+                            Expr::Add(add) => add.evaluate(),
+                            Expr::Neg(neg) => neg.evaluate(),
+                        }
+                    }
+                }
+
+                impl Evaluate for Add
+                where
+                    Box<Expr>: Evaluate,
+                {
+                    fn evaluate(&self) -> i64 {
+                        self.0.evaluate() + self.1.evaluate()
+                    }
+                }
+
+                impl Evaluate for Neg
+                where
+                    Rc<Expr>: Evaluate,
+                {
+                    fn evaluate(&self) -> i64 {
+                        -self.0.evaluate()
+                    }
+                }
+
+                impl Count for Expr
+                where
+                    Box<Expr>: Count<Out = usize>,
+                    Expr: Count<Out = usize>,
+                {
+                    type Out = usize;
+                    fn count(&mut self) -> usize {
+                        match self {
+                            Expr::Lit(_) => 1,
+                            Expr::Add(Add(l, r)) => 1 + l.count() + r.count(),
+                            Expr::Neg(Neg(e)) => 1 + Rc::get_mut(e).map_or(0, |e| e.count()),
+                        }
+                    }
+                }
+
+                // `#[track_caller]` can't go on a non-Rust-ABI forwarding method (E0737).
+                #[decycle(forward(Box))]
+                pub trait Leftmost {
+                    extern "C" fn leftmost(&self) -> i64;
+                }
+
+                impl Leftmost for Expr
+                where
+                    Box<Expr>: Leftmost,
+                {
+                    extern "C" fn leftmost(&self) -> i64 {
+                        match self {
+                            Expr::Lit(n) => *n,
+                            Expr::Add(Add(l, _)) => l.leftmost(),
+                            Expr::Neg(_) => 0,
+                        }
+                    }
+                }
+
+                pub fn lit(n: i64) -> Expr {
+                    Expr::Lit(n)
+                }
+
+                pub fn add(l: Expr, r: Expr) -> Expr {
+                    Expr::Add(Add(Box::new(l), Box::new(r)))
+                }
+
+                pub fn neg(e: Expr) -> Expr {
+                    Expr::Neg(Neg(Rc::new(e)))
+                }
+            }
+        };
+    }
+
+    forward_module!(decycle(recurse_level = 2), unbounded);
+    forward_module!(decycle(support_infinite_cycle = false), bounded);
+
+    fn eval_it<E: unbounded::Evaluate>(e: E) -> i64 {
+        e.evaluate()
+    }
+
+    fn count_it<C: unbounded::Count<Out = usize>>(mut c: C) -> usize {
+        c.count()
+    }
+
+    #[test]
+    fn forward_wrappers_unbounded() {
+        use unbounded::*;
+        // 30 levels, each odd one negating the running sum: far deeper than `recurse_level`.
+        let mut e = lit(0);
+        for n in 1..=30 {
+            e = if n % 2 == 0 { add(e, lit(n)) } else { neg(add(e, lit(n))) };
+        }
+        assert_eq!(e.evaluate(), 1);
+        assert_eq!(eval_it(&e), 1);
+        assert_eq!(eval_it(Box::new(lit(4))), 4);
+        assert_eq!(eval_it(std::rc::Rc::new(neg(lit(4)))), -4);
+        assert_eq!(e.count(), 76);
+        assert_eq!(count_it(&mut e), 76);
+        assert_eq!(count_it(Box::new(add(lit(1), lit(2)))), 3);
+        assert_eq!(add(add(lit(5), lit(1)), lit(2)).leftmost(), 5);
+    }
+
+    #[test]
+    fn forward_wrappers_bounded() {
+        use bounded::*;
+        let mut e = neg(add(add(lit(1), lit(2)), neg(lit(10))));
+        assert_eq!(e.evaluate(), 7);
+        assert_eq!(Box::new(lit(3)).evaluate(), 3);
+        assert_eq!(e.count(), 7);
+    }
+}
+
+/// Trait objects in a cycle: `impl Weigh for dyn Node` is a participant like any other self
+/// type, and `#[decycle(dyn)]` lets a cycle body call through `Box<dyn Node>` without the rank
+/// resetting at each virtual call — the vtable entry carries it (bounded mode still reaches
+/// the floor). An impl from outside the module takes the entry's default. Run in both modes.
+mod dyn_nodes {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! dyn_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle(dyn)]
+                pub trait Node {
+                    fn eval(&self) -> i64;
+                    fn len(&self) -> usize;
+
+                    // A non-Rust ABI entry gets no `#[track_caller]` (E0737).
+                    extern "C" fn tag(&self) -> u8 {
+                        0
+                    }
+                }
+
+                #[decycle]
+                pub trait Weigh {
+                    fn weigh(&self) -> i64;
+                }
+
+                pub struct List(pub Vec<Box<dyn Node>>);
+                pub struct Leaf(pub i64);
+                pub struct Scaled(pub Leaf, pub Box<dyn Node>);
+
+                impl Node for List
+                where
+                    dyn Node: Weigh,
+                {
+                    fn eval(&self) -> i64 {
+                        eval_all(&self.0)
+                    }
+
+                    // `Vec::len`, not `Node::len`: the receiver isn't a `dyn Node`.
+                    fn len(&self) -> usize {
+                        self.0.len()
+                    }
+                }
+
+                impl Node for Leaf {
+                    fn eval(&self) -> i64 {
+                        self.0
+                    }
+
+                    fn len(&self) -> usize {
+                        1
+                    }
+                }
+
+                impl Node for Scaled
+                where
+                    Leaf: Node,
+                    dyn Node: Node,
+                {
+                    fn eval(&self) -> i64 {
+                        self.0.eval() * Node::eval(&*self.1)
+                    }
+
+                    fn len(&self) -> usize {
+                        let inner: &dyn Node = &*self.1;
+                        inner.len() + 1
+                    }
+                }
+
+                // A helper's ranked copy routes its object calls too.
+                fn eval_all(children: &[Box<dyn Node>]) -> i64
+                where
+                    dyn Node: Weigh,
+                {
+                    children.iter().map(|c: &Box<dyn Node>| c.eval() + c.weigh()).sum()
+                }
+
+                impl Weigh for dyn Node {
+                    fn weigh(&self) -> i64 {
+                        1
+                    }
+                }
+            }
+        };
+    }
+
+    dyn_module!(decycle(recurse_level = 2), unbounded);
+    dyn_module!(decycle(support_infinite_cycle = false), bounded);
+
+    macro_rules! nested {
+        ($m:ident, $depth:expr) => {
+            (0..$depth).fold($m::List(vec![Box::new($m::Leaf(1))]), |s, _| {
+                $m::List(vec![Box::new(s), Box::new($m::Leaf(2))])
+            })
+        };
+    }
+
+    struct Outside;
+
+    impl unbounded::Node for Outside {
+        fn eval(&self) -> i64 {
+            5
+        }
+
+        fn len(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn dyn_nodes_unbounded() {
+        use unbounded::{Leaf, List, Node, Scaled};
+        assert_eq!(nested!(unbounded, 0).eval(), 2);
+        assert_eq!(nested!(unbounded, 1).eval(), 6);
+        assert_eq!(nested!(unbounded, 20).eval(), 82);
+        let scaled = Scaled(Leaf(3), Box::new(nested!(unbounded, 1)));
+        assert_eq!(scaled.eval(), 18);
+        let list = List(vec![Box::new(Outside), Box::new(scaled)]);
+        assert_eq!(list.eval(), 25);
+        let object: &dyn Node = &list;
+        assert_eq!(object.eval(), 25);
+        assert_eq!(object.len(), 2);
+        assert_eq!(object.tag(), 0);
+        assert_eq!(Scaled(Leaf(3), Box::new(nested!(unbounded, 4))).len(), 3);
+    }
+
+    #[test]
+    fn dyn_nodes_bounded() {
+        use bounded::{Leaf, Node, Scaled};
+        assert_eq!(nested!(bounded, 1).eval(), 6);
+        assert_eq!(Scaled(Leaf(3), Box::new(Leaf(2))).eval(), 6);
+        // Each level of `Box<dyn Node>` still counts the rank down.
+        let e = std::panic::catch_unwind(|| nested!(bounded, 20).eval()).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
@@ -10,6 +10,10 @@
 //! the `#[decycle]` module) precisely so the ranked trait wins over anything at that
 //! outer level — a trait `use` placed in an outer wrapper would leak back in there and
 //! create a genuine (unrelated) method-resolution ambiguity.
+//!
+//! After the findings come the module-surface cases added since, one wrapper mod each. Those
+//! instantiate their `#[decycle]` module in both modes from a `macro_rules!` template, and
+//! their tests only `use` its traits inside the test fns.
 
 // `mut_pattern_param` (L-M8) below writes `mut n: u32` deliberately, to exercise a
 // param pattern that used to fail to compile at all; the macro also emits a delegate
@@ -20,6 +24,8 @@
 // annotated item.
 #![allow(unused_mut)]
 
+mod common;
+
 /// L-C1: `#[decycle] use super::T as R;` used to silently DELETE impls of the renamed
 /// trait (nothing inside the consuming module ever matched the trait by its ORIGINAL
 /// name again). `finalize` now carries the local alias and renames the incoming
@@ -611,3 +617,812 @@ mod gat_delegation {
         }
     }
 }
+
+/// In-module `type` aliases behave like the types they spell: `where E: Cb` is the same edge as
+/// `where Expr<Span>: Cb`, an alias (with its default argument) may be an impl's self type, and
+/// a generic alias (`Boxed<Factor>`) expands with its arguments — so registration finds every
+/// cross-edge and unbounded mode re-enters past the floor. A generic parameter named like an
+/// alias (`T` next to `type T = Factor;`) shadows it. An alias only headers and bounds name
+/// isn't reported as unused (`deny(dead_code)`). Run in both modes.
+#[allow(dead_code)]
+mod type_aliases {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! alias_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            #[deny(dead_code)]
+            mod $mod_name {
+                #[decycle]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                pub struct Expr<S>(pub S);
+                pub struct Span;
+                pub struct Term;
+                pub struct Factor;
+
+                pub type E = Expr<Span>;
+                pub type Of<S = Span> = Expr<S>;
+                pub type Boxed<T> = Expr<Box<T>>;
+                #[allow(dead_code)]
+                pub type T = Factor;
+                #[allow(dead_code)]
+                pub type U = Span;
+
+                pub struct W<T>(pub T);
+
+                impl<T> Ca for W<T>
+                where
+                    T: Ca,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        self.0.ca(n) + self.scaled::<Span>(100)
+                    }
+                }
+
+                impl<T> W<T> {
+                    fn scaled<U>(&self, n: usize) -> usize {
+                        let _: Option<U> = None;
+                        n
+                    }
+                }
+
+                pub fn twice<U: Ca>(u: &U, n: usize) -> usize {
+                    2 * u.ca(n)
+                }
+
+                impl Ca for Term
+                where
+                    E: Cb,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            Expr(Span).cb(n - 1) + 1
+                        }
+                    }
+                }
+
+                impl Cb for Of
+                where
+                    Term: Ca,
+                    Boxed<Factor>: Cb,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        match n {
+                            0 => 0,
+                            n if n % 2 == 0 => Term.ca(n - 1) + 1,
+                            n => Expr(Box::new(Factor)).cb(n - 1) + 10,
+                        }
+                    }
+                }
+
+                impl Cb for Boxed<Factor>
+                where
+                    Factor: Ca,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        self.0.ca(n)
+                    }
+                }
+
+                impl Ca for Factor
+                where
+                    Of: Cb,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        Expr(Span).cb(n)
+                    }
+                }
+            }
+        };
+    }
+
+    alias_module!(decycle(recurse_level = 1), unbounded);
+    alias_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn type_aliases_unbounded() {
+        use unbounded::{twice, Ca, Cb, Expr, Span, Term, W};
+        assert_eq!(Term.ca(0), 0);
+        assert_eq!(W(Term).ca(1000), 1109);
+        assert_eq!(twice(&W(Term), 3), 206);
+        assert_eq!(Expr(Span).cb(3), 12);
+        assert_eq!(Term.ca(1000), 1009);
+        assert_eq!(Term.ca(1001), 1001);
+    }
+
+    #[test]
+    fn type_aliases_bounded() {
+        use bounded::{twice, Ca, Cb, Expr, Span, Term, W};
+        assert_eq!(Expr(Span).cb(3), 12);
+        assert_eq!(W(Term).ca(3), 103);
+        assert_eq!(twice(&Term, 3), 6);
+        assert_eq!(Term.ca(3), 3);
+        let e = std::panic::catch_unwind(|| Term.ca(1000)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// Impls of `#[decycle]` traits wrapped in `const _: () = { .. };` blocks, the way derive and
+/// attribute macros emit them (nested ones included), are ranked like top-level impls. The
+/// block's other items stay inside it, and a moved impl keeps the `use`s of the blocks around
+/// it (two blocks importing the same name included). Run in both modes.
+#[allow(dead_code)]
+mod const_blocks {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    mod helpers {
+        pub fn one() -> usize {
+            1
+        }
+
+        pub fn two() -> usize {
+            2
+        }
+    }
+
+    macro_rules! const_block_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                pub struct A;
+                pub struct B;
+                pub struct C;
+
+                const _: () = {
+                    use super::helpers::one;
+                    use core::fmt;
+
+                    impl Ca for A
+                    where
+                        B: Cb,
+                    {
+                        fn ca(&self, n: usize) -> usize {
+                            if n == 0 {
+                                0
+                            } else {
+                                B.cb(n - 1) + one()
+                            }
+                        }
+                    }
+
+                    impl fmt::Display for B {
+                        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            f.write_str("B")
+                        }
+                    }
+
+                    const _: () = {
+                        use super::helpers::two;
+
+                        impl self::Cb for B
+                        where
+                            A: Ca,
+                        {
+                            fn cb(&self, n: usize) -> usize {
+                                if n == 0 {
+                                    0
+                                } else {
+                                    A.ca(n - 1) + two()
+                                }
+                            }
+                        }
+                    };
+                };
+
+                const _: () = {
+                    use super::helpers::one;
+
+                    impl Cb for C {
+                        fn cb(&self, n: usize) -> usize {
+                            n + one()
+                        }
+                    }
+                };
+            }
+        };
+    }
+
+    const_block_module!(decycle(recurse_level = 2), unbounded);
+    const_block_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn const_blocks_unbounded() {
+        use unbounded::{Ca, Cb};
+        assert_eq!(unbounded::A.ca(0), 0);
+        assert_eq!(unbounded::A.ca(3), 4);
+        assert_eq!(unbounded::B.cb(3), 5);
+        assert_eq!(unbounded::A.ca(1000), 1500);
+        assert_eq!(unbounded::B.to_string(), "B");
+        assert_eq!(unbounded::C.cb(3), 4);
+    }
+
+    #[test]
+    fn const_blocks_bounded() {
+        use bounded::{Ca, Cb};
+        assert_eq!(bounded::A.ca(3), 4);
+        assert_eq!(bounded::B.cb(3), 5);
+        assert_eq!(bounded::C.cb(3), 4);
+        let e = std::panic::catch_unwind(|| bounded::A.ca(100)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// `#[cfg]` on a `#[decycle]` trait, an impl or a single method gates everything decycle derives
+/// from it, down to the `dyn` entries and forwarding impls, and `#[cfg_attr(pred, decycle)]`
+/// makes a trait a cycle participant only where `pred` holds. Each module is instantiated with
+/// its predicate on (`all()`) and off (`any()`), in both modes, next to markers depending on
+/// real options (`debug_assertions`, its `not(..)`, and `debug_assertions` with `unix`).
+#[allow(dead_code)]
+mod cfg_gating {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! cfg_module {
+        ($decycle_attr:meta, $mod_name:ident, $on:meta) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[cfg_attr(all(), decycle)]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> usize;
+
+                    #[cfg($on)]
+                    fn ca_twice(&self, n: usize) -> usize
+                    where
+                        B: Cb,
+                    {
+                        B.cb(n) * 2
+                    }
+                }
+
+                #[decycle(dyn, forward(Box))]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+
+                    #[cfg($on)]
+                    fn cb_on(&self, n: usize) -> usize;
+                }
+
+                #[cfg($on)]
+                #[decycle(forward(Box))]
+                pub trait Cd {
+                    fn cd(&self, n: usize) -> usize;
+                }
+
+                #[cfg_attr($on, decycle)]
+                pub trait Cc {
+                    fn cc(&self) -> usize;
+                }
+
+                #[cfg_attr(all(debug_assertions, $on), decycle)]
+                pub trait Ce {
+                    fn ce(&self) -> usize;
+                }
+
+                #[cfg_attr(not(debug_assertions), decycle)]
+                pub trait Cf {
+                    fn cf(&self) -> usize;
+                }
+
+                #[cfg_attr(any(unix, not(debug_assertions)), decycle)]
+                pub trait Cg {
+                    fn cg(&self) -> usize;
+                }
+
+                pub struct A;
+                pub struct B;
+                pub struct C;
+                pub struct D;
+
+                #[cfg($on)]
+                impl Ca for A
+                where
+                    B: Cb,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            B.cb(n - 1) + 1
+                        }
+                    }
+                }
+
+                #[cfg(not($on))]
+                impl Ca for A
+                where
+                    B: Cb,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            B.cb(n - 1) + 2
+                        }
+                    }
+                }
+
+                impl Cb for B
+                where
+                    A: Ca,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            A.ca(n - 1) + 10
+                        }
+                    }
+
+                    #[cfg($on)]
+                    fn cb_on(&self, n: usize) -> usize {
+                        A.ca(n) + 100
+                    }
+                }
+
+                #[cfg($on)]
+                impl Cd for D
+                where
+                    D: Cd,
+                    B: Cb,
+                {
+                    fn cd(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            self.cd(n - 1) + B.cb(1)
+                        }
+                    }
+                }
+
+                impl Cc for C {
+                    fn cc(&self) -> usize {
+                        7
+                    }
+                }
+
+                impl Ce for C {
+                    fn ce(&self) -> usize {
+                        8
+                    }
+                }
+
+                impl Cf for C {
+                    fn cf(&self) -> usize {
+                        9
+                    }
+                }
+
+                impl Cg for C {
+                    fn cg(&self) -> usize {
+                        10
+                    }
+                }
+            }
+        };
+    }
+
+    cfg_module!(decycle(recurse_level = 2), unbounded_on, all());
+    cfg_module!(decycle(recurse_level = 2), unbounded_off, any());
+    cfg_module!(decycle(support_infinite_cycle = false), bounded_on, all());
+    cfg_module!(decycle(support_infinite_cycle = false), bounded_off, any());
+
+    #[test]
+    fn cfg_gating_unbounded() {
+        {
+            use unbounded_on::{Ca, Cb, Cc, Cd, Ce, Cf, Cg};
+            assert_eq!(unbounded_on::A.ca(3), 12);
+            assert_eq!(unbounded_on::B.cb(3), 21);
+            assert_eq!(unbounded_on::A.ca_twice(3), 42);
+            assert_eq!(unbounded_on::B.cb_on(3), 112);
+            assert_eq!(Box::new(unbounded_on::B).cb_on(3), 112);
+            assert_eq!((&unbounded_on::B as &dyn Cb).cb(3), 21);
+            assert_eq!(Box::new(unbounded_on::D).cd(5), 50);
+            assert_eq!(unbounded_on::D.cd(5), 50);
+            assert_eq!(unbounded_on::A.ca(1000), 5500);
+            assert_eq!(unbounded_on::C.cc(), 7);
+            assert_eq!(unbounded_on::C.ce(), 8);
+            assert_eq!(unbounded_on::C.cf(), 9);
+            assert_eq!(unbounded_on::C.cg(), 10);
+        }
+        {
+            use unbounded_off::{Ca, Cb, Cc, Ce};
+            assert_eq!(unbounded_off::A.ca(3), 14);
+            assert_eq!(unbounded_off::B.cb(3), 22);
+            assert_eq!((&unbounded_off::B as &dyn Cb).cb(3), 22);
+            assert_eq!(unbounded_off::A.ca(1000), 6000);
+            assert_eq!(unbounded_off::C.cc(), 7);
+            assert_eq!(unbounded_off::C.ce(), 8);
+        }
+    }
+
+    #[test]
+    fn cfg_gating_bounded() {
+        {
+            use bounded_on::{Ca, Cb, Cc, Cd, Cg};
+            assert_eq!(bounded_on::A.ca(3), 12);
+            assert_eq!(bounded_on::B.cb_on(1), 101);
+            assert_eq!(bounded_on::D.cd(1), 10);
+            assert_eq!(bounded_on::C.cc(), 7);
+            assert_eq!(bounded_on::C.cg(), 10);
+            let e = std::panic::catch_unwind(|| bounded_on::A.ca(100)).unwrap_err();
+            assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+        }
+        {
+            use bounded_off::{Ca, Cb};
+            assert_eq!(bounded_off::A.ca(3), 14);
+            assert_eq!(bounded_off::B.cb(3), 22);
+            let e = std::panic::catch_unwind(|| bounded_off::A.ca(100)).unwrap_err();
+            assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+        }
+    }
+}
+
+/// Where the attributes on an impl's methods go (the "Attributes" table in the crate docs).
+/// `#[track_caller]` is on every copy between a caller and the floor, `dyn` entries included,
+/// so `Location::caller()` at the end of a cycle is the caller's call site. A `#[deprecated]`
+/// trait method is only reported where the user calls it (`deny(deprecated)` below), not at
+/// decycle's own calls. Run in both modes.
+#[allow(dead_code)]
+#[deny(deprecated)]
+mod attribute_policy {
+    use decycle::decycle;
+
+    macro_rules! attribute_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                use std::panic::Location;
+
+                #[decycle]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> &'static Location<'static>;
+
+                    #[deprecated(note = "use `ca`")]
+                    fn old(&self) -> usize {
+                        0
+                    }
+                }
+
+                #[decycle(dyn, forward(Box))]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> &'static Location<'static>;
+
+                    #[deprecated(note = "use `cb`")]
+                    fn old_b(&self) -> usize {
+                        0
+                    }
+                }
+
+                pub struct A;
+                pub struct B;
+
+                impl Ca for A
+                where
+                    B: Cb,
+                {
+                    #[track_caller]
+                    #[inline]
+                    fn ca(&self, n: usize) -> &'static Location<'static> {
+                        if n == 0 {
+                            Location::caller()
+                        } else {
+                            B.cb(n - 1)
+                        }
+                    }
+                }
+
+                impl Cb for B
+                where
+                    A: Ca,
+                {
+                    #[track_caller]
+                    fn cb(&self, n: usize) -> &'static Location<'static> {
+                        A.ca(n)
+                    }
+                }
+            }
+        };
+    }
+
+    attribute_module!(decycle(recurse_level = 4), unbounded);
+    attribute_module!(decycle(recurse_level = 4, support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn attribute_policy_unbounded() {
+        use unbounded::{Ca, Cb};
+        let (loc, line) = (unbounded::A.ca(0), line!());
+        assert_eq!((loc.file(), loc.line()), (file!(), line));
+        let (loc, line) = (unbounded::A.ca(1), line!());
+        assert_eq!((loc.file(), loc.line()), (file!(), line));
+        let (loc, line) = (Box::new(unbounded::B).cb(1), line!());
+        assert_eq!((loc.file(), loc.line()), (file!(), line));
+    }
+
+    #[test]
+    fn attribute_policy_bounded() {
+        use bounded::{Ca, Cb};
+        let (loc, line) = (bounded::A.ca(1), line!());
+        assert_eq!((loc.file(), loc.line()), (file!(), line));
+        let (loc, line) = (bounded::B.cb(1), line!());
+        assert_eq!((loc.file(), loc.line()), (file!(), line));
+    }
+}
+
+/// `#[decycle(expand_first)]` on a trait or an impl runs its other attribute macros before the
+/// module is decycled, so what they emit is what gets ranked: `async_box` rewrites signatures
+/// and bodies the way `async_trait` does, and `count_calls` counts each body once and emits an
+/// item beside its impl. Run in both modes.
+#[allow(dead_code)]
+mod expand_first {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! expand_first_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                use attrmacros::{async_box, count_calls};
+                use core::sync::atomic::AtomicUsize;
+
+                pub static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+                #[decycle(expand_first)]
+                #[async_box]
+                pub trait Ca {
+                    async fn ca(&self, n: usize) -> usize;
+                }
+
+                #[async_box]
+                #[decycle(dyn, expand_first)]
+                pub trait Cb {
+                    async fn cb(&self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cc {
+                    fn cc(&self, n: usize) -> usize;
+                }
+
+                pub struct A;
+                pub struct B;
+                pub struct C;
+
+                #[decycle(expand_first)]
+                #[async_box]
+                impl Ca for A
+                where
+                    B: Cb,
+                {
+                    async fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            B.cb(n - 1).await + 1
+                        }
+                    }
+                }
+
+                #[async_box]
+                #[cfg(all())]
+                #[decycle(expand_first)]
+                impl Cb for B
+                where
+                    A: Ca,
+                    C: Cc,
+                {
+                    async fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            C.cc(0)
+                        } else {
+                            A.ca(n - 1).await + 10
+                        }
+                    }
+                }
+
+                #[cfg(any())]
+                #[decycle(expand_first)]
+                #[count_calls]
+                impl Cc for A {
+                    fn cc(&self, n: usize) -> usize {
+                        n
+                    }
+                }
+
+                #[count_calls]
+                #[decycle(expand_first)]
+                impl Cc for C
+                where
+                    C: Cc,
+                {
+                    fn cc(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            self.cc(n - 1) + 2
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    expand_first_module!(decycle(recurse_level = 2), unbounded);
+    expand_first_module!(decycle(support_infinite_cycle = false), bounded);
+
+    /// Polls `future` to completion; the futures here never wait.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+        const VTABLE: RawWakerVTable =
+            RawWakerVTable::new(
+                |_| RawWaker::new(std::ptr::null(), &VTABLE),
+                |_| {},
+                |_| {},
+                |_| {},
+            );
+        let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+        let mut future = Box::pin(future);
+        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn expand_first_unbounded() {
+        use core::sync::atomic::Ordering;
+        use unbounded::{Ca, Cb, Cc};
+        assert_eq!(block_on(unbounded::A.ca(0)), 0);
+        assert_eq!(block_on(unbounded::A.ca(3)), 12);
+        assert_eq!(block_on(unbounded::B.cb(3)), 21);
+        assert_eq!(block_on((&unbounded::B as &dyn Cb).cb(3)), 21);
+        assert_eq!(block_on(unbounded::A.ca(1000)), 5500);
+        // `B.cb(0)` called `C.cc(0)` once.
+        assert_eq!(unbounded::CALLS.load(Ordering::Relaxed), 1);
+        assert_eq!(unbounded::C.cc(3), 6);
+        assert_eq!(unbounded::CALLS.load(Ordering::Relaxed), 5);
+        assert_eq!(unbounded::COUNTED_C, "C");
+    }
+
+    #[test]
+    fn expand_first_bounded() {
+        use bounded::{Ca, Cc};
+        assert_eq!(block_on(bounded::A.ca(3)), 12);
+        assert_eq!(bounded::C.cc(1), 2);
+        let e = std::panic::catch_unwind(|| block_on(bounded::A.ca(100))).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// A `#[decycle]` module declared inside a fn body: in a `#[test]`, twice under the same name
+/// in sibling blocks, and reached through a crate-level `#[decycle]` trait. A module can't name
+/// its fn's items, so its traits are declared inside it. Run in both modes. Next to them, the
+/// same `#[decycle(local)]` trait declared in two fns, whose carriers stay in their blocks.
+#[allow(dead_code)]
+mod local_items {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    #[decycle]
+    pub trait Cx {
+        fn cx(&self, n: usize) -> usize;
+    }
+
+    macro_rules! local_module {
+        ($decycle_attr:meta, $step:expr) => {
+            #[$decycle_attr]
+            mod m {
+                #[decycle]
+                use super::Cx;
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                pub struct A;
+                pub struct B;
+
+                impl Cx for A
+                where
+                    B: Cb,
+                {
+                    fn cx(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            B.cb(n - 1) + $step
+                        }
+                    }
+                }
+
+                impl Cb for B
+                where
+                    A: Cx,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            A.cx(n - 1) + 10
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn local_items_unbounded() {
+        let one = {
+            local_module!(decycle(recurse_level = 2), 1);
+            m::A.cx(1000)
+        };
+        let two = {
+            local_module!(decycle(recurse_level = 2), 2);
+            m::A.cx(1000)
+        };
+        assert_eq!((one, two), (5500, 6000));
+    }
+
+    macro_rules! local_trait {
+        ($n:expr) => {{
+            #[decycle(local)]
+            pub trait Cl {
+                fn cl(&self) -> usize;
+            }
+
+            struct L;
+
+            impl Cl for L {
+                fn cl(&self) -> usize {
+                    $n
+                }
+            }
+
+            L.cl()
+        }};
+    }
+
+    fn local_trait_one() -> usize {
+        local_trait!(1)
+    }
+
+    fn local_trait_two() -> usize {
+        local_trait!(2)
+    }
+
+    #[test]
+    fn local_items_local_traits() {
+        assert_eq!((local_trait_one(), local_trait_two()), (1, 2));
+    }
+
+    #[test]
+    fn local_items_bounded() {
+        local_module!(decycle(support_infinite_cycle = false), 1);
+        assert_eq!(m::A.cx(3), 12);
+        let e = std::panic::catch_unwind(|| m::A.cx(100)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
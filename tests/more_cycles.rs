@@ -319,3 +319,175 @@ mod async_like_loops {
         }
     }
 }
+
+/// Associated-type projections consumed INSIDE cycle bodies. Each `#[decycle]` trait with
+/// associated types gets a rank-free types trait; `<L as Evaluate>::Output`, `L::Output` and
+/// `Self::Output` all project through it, so they name the same type at every rank — a
+/// `Binary`-style node can bind its operand's result to `<Lhs as Evaluate>::Output`, and a
+/// concrete cross-edge result (`<Odd as Evaluate>::Output`) normalizes to `u64` inside the
+/// ranked copy of the body. Run in both modes, past `recurse_level` in the unbounded one.
+mod assoc_projection {
+    use decycle::decycle;
+
+    macro_rules! parity_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Evaluate {
+                    type Output;
+                    fn evaluate(&self, n: u64) -> Self::Output;
+                }
+
+                pub struct Even;
+                pub struct Odd;
+                pub struct Pair<Lhs>(pub Lhs);
+
+                impl Evaluate for Even
+                where
+                    Odd: Evaluate,
+                    Pair<Odd>: Evaluate,
+                {
+                    type Output = u64;
+                    fn evaluate(&self, n: u64) -> u64 {
+                        if n == 0 {
+                            return 0;
+                        }
+                        let odd: <Odd as Evaluate>::Output = Odd.evaluate(n - 1);
+                        if n == 1 {
+                            let (a, b): <Pair<Odd> as Evaluate>::Output = Pair(Odd).evaluate(0);
+                            return a + b;
+                        }
+                        odd
+                    }
+                }
+
+                impl Evaluate for Odd
+                where
+                    Even: Evaluate,
+                {
+                    type Output = u64;
+                    fn evaluate(&self, n: u64) -> u64 {
+                        let even: <Even as Evaluate>::Output = Even.evaluate(n);
+                        even + 1
+                    }
+                }
+
+                impl<Lhs> Evaluate for Pair<Lhs>
+                where
+                    Lhs: Evaluate,
+                {
+                    type Output = (Lhs::Output, <Lhs as Evaluate>::Output);
+                    fn evaluate(&self, n: u64) -> Self::Output {
+                        let lhs: <Lhs as Evaluate>::Output = self.0.evaluate(n);
+                        let again: Lhs::Output = self.0.evaluate(n);
+                        (lhs, again)
+                    }
+                }
+            }
+        };
+    }
+
+    parity_module!(decycle(recurse_level = 2), unbounded);
+    parity_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn projections_agree_across_ranks_unbounded() {
+        use unbounded::Evaluate;
+        // Even(n) descends through Odd/Even n times — far past `recurse_level = 2`.
+        assert_eq!(unbounded::Even.evaluate(1), 2);
+        assert_eq!(unbounded::Even.evaluate(41), 42);
+        let (a, b): (u64, u64) = unbounded::Pair(unbounded::Odd).evaluate(40);
+        assert_eq!((a, b), (42, 42));
+    }
+
+    #[test]
+    fn projections_agree_across_ranks_bounded() {
+        use bounded::Evaluate;
+        assert_eq!(bounded::Even.evaluate(3), 4);
+        let (a, b): (u64, u64) = bounded::Pair(bounded::Even).evaluate(2);
+        assert_eq!((a, b), (3, 3));
+    }
+}
+
+/// `Assoc = X` constraints on a `#[decycle]` bound may pin any bounded type, not only `Self`
+/// or the impl's own type parameters: `Neg: Evaluate<Output = i64>` on a concrete sibling is
+/// carried through every rank on the types trait (the ranked trait doesn't declare `Output`),
+/// alongside the parameter-bounded `T: Evaluate<Output = i64>`. Run in both modes, past
+/// `recurse_level` in the unbounded one.
+mod assoc_constraint_bounds {
+    use decycle::decycle;
+
+    macro_rules! pinned_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                #[decycle]
+                pub trait Evaluate {
+                    type Output;
+                    fn evaluate(&self, n: u32) -> Self::Output;
+                }
+
+                pub struct Lit;
+                pub struct Neg;
+                pub struct Wrap<T>(pub T);
+
+                impl Evaluate for Lit
+                where
+                    Neg: Evaluate<Output = i64>,
+                    Wrap<Neg>: Evaluate<Output = i64>,
+                {
+                    type Output = i64;
+                    fn evaluate(&self, n: u32) -> i64 {
+                        if n == 0 {
+                            1
+                        } else if n == 1 {
+                            Wrap(Neg).evaluate(0)
+                        } else {
+                            Neg.evaluate(n - 1)
+                        }
+                    }
+                }
+
+                impl Evaluate for Neg
+                where
+                    Lit: Evaluate<Output = i64>,
+                {
+                    type Output = i64;
+                    fn evaluate(&self, n: u32) -> i64 {
+                        -Lit.evaluate(n)
+                    }
+                }
+
+                impl<T> Evaluate for Wrap<T>
+                where
+                    T: Evaluate<Output = i64>,
+                {
+                    type Output = i64;
+                    fn evaluate(&self, n: u32) -> Self::Output {
+                        self.0.evaluate(n)
+                    }
+                }
+            }
+        };
+    }
+
+    pinned_module!(decycle(recurse_level = 2), unbounded);
+    pinned_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn constraints_on_concrete_siblings_unbounded() {
+        use unbounded::Evaluate;
+        assert_eq!(unbounded::Lit.evaluate(1), -1);
+        assert_eq!(unbounded::Lit.evaluate(40), 1);
+        assert_eq!(unbounded::Lit.evaluate(41), -1);
+        assert_eq!(unbounded::Wrap(unbounded::Neg).evaluate(2), -1);
+    }
+
+    #[test]
+    fn constraints_on_concrete_siblings_bounded() {
+        use bounded::Evaluate;
+        assert_eq!(bounded::Lit.evaluate(3), -1);
+        assert_eq!(bounded::Neg.evaluate(2), -1);
+    }
+}
@@ -0,0 +1,40 @@
+//! A blanket impl bounded only by a `#[decycle]` trait next to a concrete impl of the same
+//! trait: the originals are disjoint (`A: Cb` can't hold), but the ranked copies' free rank
+//! keeps rustc from proving it, so the shape is rejected on the blanket impl.
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+    }
+
+    #[decycle]
+    pub trait Cb {
+        fn cb(&self, n: usize) -> usize;
+    }
+
+    pub struct A;
+    pub struct B;
+
+    impl<T: Cb> Ca for T {
+        fn ca(&self, n: usize) -> usize {
+            self.cb(n) + 1
+        }
+    }
+
+    impl Ca for A {
+        fn ca(&self, n: usize) -> usize {
+            n
+        }
+    }
+
+    impl Cb for B {
+        fn cb(&self, n: usize) -> usize {
+            n
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! The module is expanded once for each combination of the `cfg` options its `cfg_attr` markers
+//! depend on, so markers depending on more than three are rejected rather than doubling it again.
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[cfg_attr(all(unix, debug_assertions), decycle)]
+    pub trait Ca {
+        fn ca(&self) -> usize;
+    }
+
+    #[cfg_attr(any(not(unix), panic = "unwind", target_pointer_width = "64"), decycle)]
+    pub trait Cb {
+        fn cb(&self) -> usize;
+    }
+}
+
+fn main() {}
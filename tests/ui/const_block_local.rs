@@ -0,0 +1,49 @@
+//! An impl moved out of its `const _` block to be ranked can't take the block's own items along:
+//! naming one is rejected, rather than failing to resolve outside the block.
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+    }
+
+    #[decycle]
+    pub trait Cb {
+        fn cb(&self, n: usize) -> usize;
+    }
+
+    pub struct A;
+    pub struct B;
+
+    impl Ca for A
+    where
+        B: Cb,
+    {
+        fn ca(&self, n: usize) -> usize {
+            if n == 0 { 0 } else { B.cb(n - 1) + 1 }
+        }
+    }
+
+    const _: () = {
+        struct Helper;
+
+        impl Helper {
+            fn step(&self) -> usize {
+                1
+            }
+        }
+
+        impl Cb for B
+        where
+            A: Ca,
+        {
+            fn cb(&self, n: usize) -> usize {
+                if n == 0 { 0 } else { A.ca(n - 1) + Helper.step() }
+            }
+        }
+    };
+}
+
+fn main() {}
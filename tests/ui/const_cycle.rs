@@ -0,0 +1,32 @@
+//! Const recursion that never bottoms out stops at the floor with decycle's own message
+//! instead of rustc's cycle error (E0391) through the Final impls.
+use decycle::decycle;
+
+#[decycle(recurse_level = 2)]
+mod m {
+    #[decycle]
+    pub trait Node {
+        const PREC: usize;
+    }
+
+    pub struct Sum;
+    pub struct Prod;
+
+    impl Node for Sum
+    where
+        Prod: Node,
+    {
+        const PREC: usize = <Prod as Node>::PREC - 1;
+    }
+
+    impl Node for Prod
+    where
+        Sum: Node,
+    {
+        const PREC: usize = <Sum as Node>::PREC + 2;
+    }
+}
+
+fn main() {
+    let _ = <m::Sum as m::Node>::PREC;
+}
@@ -0,0 +1,41 @@
+//! A default method that calls into the cycle without a bound is copied into each impl and
+//! ranked against the impl's bounds — so an impl with no `#[decycle]` bound at all is an error
+//! on the call, rather than an unresolvable ranked call.
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+
+        fn hop(&self, n: usize) -> usize {
+            B.cb(n) + 1
+        }
+    }
+
+    #[decycle]
+    pub trait Cb {
+        fn cb(&self, n: usize) -> usize;
+    }
+
+    pub struct A;
+    pub struct B;
+
+    impl Ca for A {
+        fn ca(&self, n: usize) -> usize {
+            n
+        }
+    }
+
+    impl Cb for B
+    where
+        A: Ca,
+    {
+        fn cb(&self, n: usize) -> usize {
+            A.ca(n)
+        }
+    }
+}
+
+fn main() {}
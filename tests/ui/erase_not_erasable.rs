@@ -0,0 +1,48 @@
+//! `#[decycle(erase)]` re-enters a method through a value of another type, so a method that
+//! can't be called on it (`&mut self`, generics of its own, another `Self` in the signature)
+//! is an error rather than an impl left without its re-entry.
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+
+        fn bump(&mut self, n: usize) -> usize;
+    }
+
+    pub struct A;
+    pub struct B;
+
+    #[decycle(erase)]
+    impl Ca for A {
+        fn ca(&self, n: usize) -> usize {
+            n
+        }
+
+        fn bump(&mut self, n: usize) -> usize {
+            n + 1
+        }
+    }
+
+    impl decycle::Erase for A {
+        type Erased = B;
+
+        fn erase(&self) -> B {
+            B
+        }
+    }
+
+    impl Ca for B {
+        fn ca(&self, n: usize) -> usize {
+            n
+        }
+
+        fn bump(&mut self, n: usize) -> usize {
+            n
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+//! An erased method re-enters at one instantiation, so every one of its type params must be
+//! erased.
+use decycle::decycle;
+
+pub trait Stream {
+    fn advance(&mut self) -> Option<u8>;
+}
+
+#[decycle]
+mod m {
+    use crate::Stream;
+
+    #[decycle]
+    pub trait Ca {
+        #[decycle(erase(S => dyn Stream))]
+        fn ca<S: Stream, T: Copy>(&self, stream: &mut S, t: T) -> usize;
+    }
+
+    pub struct A;
+
+    impl Ca for A {
+        fn ca<S: Stream, T: Copy>(&self, _stream: &mut S, _t: T) -> usize {
+            0
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+//! A `&mut self` method forwarded through a wrapper that is only `Deref` is reported on the
+//! wrapper's name in `forward(..)`.
+use std::ops::Deref;
+
+pub struct Shared<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[decycle::decycle]
+mod m {
+    #[decycle(forward(Box, super::Shared))]
+    pub trait Bump {
+        fn bump(&mut self) -> usize;
+    }
+
+    pub struct A;
+
+    impl Bump for A {
+        fn bump(&mut self) -> usize {
+            1
+        }
+    }
+}
+
+fn main() {}
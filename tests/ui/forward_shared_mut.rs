@@ -0,0 +1,17 @@
+#[decycle::decycle]
+mod m {
+    #[decycle(forward(Box, std::rc::Rc))]
+    pub trait Bump {
+        fn bump(&mut self) -> usize;
+    }
+
+    pub struct A;
+
+    impl Bump for A {
+        fn bump(&mut self) -> usize {
+            1
+        }
+    }
+}
+
+fn main() {}
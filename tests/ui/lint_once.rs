@@ -0,0 +1,47 @@
+//! A lint in a cycle body fires once: the body is compiled in one copy, which keeps the
+//! user's lint levels. Of the lints, only `unused_variables` is allowed on that copy, as
+//! it always has been.
+#![deny(unused_mut, unused_must_use)]
+
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+    }
+
+    #[decycle]
+    pub trait Cb {
+        #[must_use]
+        fn cb(&self, n: usize) -> usize;
+    }
+
+    pub struct A;
+    pub struct B;
+
+    impl Ca for A
+    where
+        B: Cb,
+    {
+        fn ca(&self, n: usize) -> usize {
+            let mut unused = n;
+            B.cb(unused);
+            0
+        }
+    }
+
+    impl Cb for B
+    where
+        A: Ca,
+    {
+        #[allow(unused_mut)]
+        fn cb(&self, n: usize) -> usize {
+            let mut allowed = n;
+            A.ca(allowed)
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+//! A method's #[decycle] bound becomes an edge of its whole impl. One naming the impl's generics
+//! would narrow the impl to where it holds (`W<u8>: Ca` without `W<u8>: Cb` here), so it's
+//! rejected rather than silently moved.
+use decycle::decycle;
+
+#[decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self) -> usize;
+
+        fn ca_via_cb(&self, n: usize) -> usize
+        where
+            Self: Cb;
+    }
+
+    #[decycle]
+    pub trait Cb {
+        fn cb(&self, n: usize) -> usize;
+    }
+
+    pub struct W<T>(pub T);
+
+    impl<T> Ca for W<T> {
+        fn ca(&self) -> usize {
+            1
+        }
+
+        fn ca_via_cb(&self, n: usize) -> usize
+        where
+            Self: Cb,
+        {
+            self.cb(n) + 1
+        }
+    }
+
+    impl Cb for W<u16>
+    where
+        W<u16>: Ca,
+    {
+        fn cb(&self, n: usize) -> usize {
+            if n == 0 { 0 } else { self.ca_via_cb(n - 1) }
+        }
+    }
+}
+
+fn main() {}
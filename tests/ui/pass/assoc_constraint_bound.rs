@@ -1,3 +1,6 @@
+//! An `Assoc = X` constraint on a `#[decycle]` bound whose bounded type is neither `Self` nor
+//! one of the impl's type parameters (`(): MyTrait<'b, MyTrait = T, T = T>`) is accepted: the
+//! constraints move onto the trait's types trait, so the ranked bound doesn't need them.
 use decycle::decycle;
 
 #[allow(dead_code)]
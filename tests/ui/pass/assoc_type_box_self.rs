@@ -1,6 +1,6 @@
-//! L-m4: `check_assoc_type_self` used to only catch a bare `type Assoc = Self;` — a
-//! `Self` occurring anywhere in the assigned type (`Box<Self>`, `(Self,)`, …) recreates
-//! exactly the same infinite recursive definition and is now caught too.
+//! L-m4 follow-up: a `Self` anywhere in the assigned type (`Box<Self>`) type-checks too, as
+//! long as the assigned type really implements the bound — here through its own impl inside
+//! the cycle.
 #[decycle::decycle]
 mod m {
     #[decycle]
@@ -39,6 +39,20 @@ mod m {
             }
         }
     }
+
+    impl Loop for Box<A>
+    where
+        A: Loop,
+    {
+        type Assoc = Self;
+        fn step(&self, n: u32) -> u32 {
+            (**self).step(n)
+        }
+    }
 }
 
-fn main() {}
+fn main() {
+    use m::Loop;
+    let boxed: <m::A as Loop>::Assoc = Box::new(m::A);
+    assert_eq!(boxed.step(25), 25);
+}
@@ -0,0 +1,50 @@
+//! `type Assoc = Self;` on an associated type bounded by a `#[decycle]` trait. The assignment
+//! lives on the rank-free types trait, which declares `Assoc` without the bound; the impl takes
+//! `S: Foo` as a ranked edge instead — no longer an infinite recursive definition.
+#[decycle::decycle]
+mod m {
+    #[decycle]
+    pub trait Foo {
+        type Assoc: Foo;
+        fn foo(&self, n: u32) -> u32;
+    }
+
+    #[decycle]
+    pub trait Bar {
+        fn bar(&self, n: u32) -> u32;
+    }
+
+    pub struct S;
+
+    impl Foo for S
+    where
+        S: Bar,
+    {
+        type Assoc = Self;
+        fn foo(&self, n: u32) -> u32 {
+            if n == 0 {
+                0
+            } else {
+                S.bar(n - 1) + 1
+            }
+        }
+    }
+
+    impl Bar for S
+    where
+        S: Foo,
+    {
+        fn bar(&self, n: u32) -> u32 {
+            S.foo(n)
+        }
+    }
+}
+
+fn assoc_foo<T: m::Foo>(t: T::Assoc, n: u32) -> u32 {
+    use m::Foo;
+    t.foo(n)
+}
+
+fn main() {
+    assert_eq!(assoc_foo::<m::S>(m::S, 30), 30);
+}
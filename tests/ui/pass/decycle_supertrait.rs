@@ -0,0 +1,79 @@
+//! L-M3 follow-up: a #[decycle] trait may be a supertrait of another #[decycle] trait in the
+//! same batch. The ranked trait inherits the ranked supertrait at the same rank
+//! (`DerivedRanked<R>: BaseRanked<R>`), and each ranked impl requires its supertrait impl at
+//! that rank — even when, like `Base for A` here, it needs bounds the `Derived` impl lacks —
+//! and a `Derived` body calls `Base` methods at its own rank.
+#[decycle::decycle]
+mod m {
+    #[decycle]
+    pub trait Base {
+        fn base(&self, n: u32) -> u32;
+    }
+
+    #[decycle]
+    pub trait Derived: Base {
+        fn derived(&self, n: u32) -> u32;
+    }
+
+    pub struct A;
+    pub struct B;
+    pub struct C;
+
+    impl Base for A
+    where
+        C: Base,
+    {
+        fn base(&self, n: u32) -> u32 {
+            if n == 0 {
+                1
+            } else {
+                C.base(n - 1)
+            }
+        }
+    }
+    impl Base for B
+    where
+        A: Base,
+    {
+        fn base(&self, n: u32) -> u32 {
+            A.base(n) + 1
+        }
+    }
+    impl Base for C
+    where
+        B: Base,
+    {
+        fn base(&self, n: u32) -> u32 {
+            B.base(n)
+        }
+    }
+    impl Derived for A
+    where
+        B: Derived,
+    {
+        fn derived(&self, n: u32) -> u32 {
+            if n == 0 {
+                0
+            } else {
+                B.derived(n - 1) + 1
+            }
+        }
+    }
+    impl Derived for B
+    where
+        A: Derived,
+    {
+        fn derived(&self, n: u32) -> u32 {
+            A.derived(n) + self.base(0) + A.base(0) - 3
+        }
+    }
+}
+
+fn derived_and_base<T: m::Derived>(t: &T, n: u32) -> (u32, u32) {
+    (t.derived(n), t.base(n))
+}
+
+fn main() {
+    assert_eq!(derived_and_base(&m::A, 30), (30, 31));
+    assert_eq!(derived_and_base(&m::B, 4), (4, 6));
+}
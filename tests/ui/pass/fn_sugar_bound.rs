@@ -0,0 +1,70 @@
+//! A #[decycle] trait may be named with `Fn(...)`-sugar in a bound, inline in the impl's
+//! generics or in its where-clause: `F: Step(usize) -> usize` is desugared to
+//! `F: Step<(usize,), Output = usize>` before ranking, so the Rank argument is inserted into
+//! the angle-bracketed form and the `Output` constraint is carried by the types trait.
+#[decycle::decycle]
+mod m {
+    #[decycle]
+    pub trait Step<Args> {
+        type Output;
+        fn step(&self, args: Args) -> Self::Output;
+    }
+
+    pub struct Pow2;
+    pub struct Count;
+    pub struct Twice<F>(pub F);
+    pub struct Plus1<F>(pub F);
+
+    impl Step<(usize,)> for Pow2
+    where
+        Twice<Pow2>: Step<(usize,)>,
+    {
+        type Output = usize;
+        fn step(&self, (n,): (usize,)) -> usize {
+            if n == 0 {
+                1
+            } else {
+                Twice(Pow2).step((n - 1,))
+            }
+        }
+    }
+
+    impl Step<(usize,)> for Count
+    where
+        Plus1<Count>: Step<(usize,)>,
+    {
+        type Output = usize;
+        fn step(&self, (n,): (usize,)) -> usize {
+            if n == 0 {
+                0
+            } else {
+                Plus1(Count).step((n - 1,))
+            }
+        }
+    }
+
+    impl<F: Step(usize) -> usize> Step<(usize,)> for Twice<F> {
+        type Output = usize;
+        fn step(&self, args: (usize,)) -> Self::Output {
+            2 * self.0.step(args)
+        }
+    }
+
+    impl<F> Step<(usize,)> for Plus1<F>
+    where
+        F: Step(usize) -> usize,
+    {
+        type Output = usize;
+        fn step(&self, args: (usize,)) -> Self::Output {
+            let inner: usize = self.0.step(args);
+            inner + 1
+        }
+    }
+}
+
+fn main() {
+    use m::Step;
+    // Deep enough to pass the default `recurse_level`.
+    assert_eq!(m::Pow2.step((10,)), 1024);
+    assert_eq!(m::Count.step((40,)), 40);
+}
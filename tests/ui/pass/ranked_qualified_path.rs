@@ -0,0 +1,58 @@
+//! `#[decycle(ranked)]` on an impl opts its qualified references to #[decycle] traits into
+//! ranking: `impl crate::m::Ca for A` and `B: super::m::Cb` are treated exactly like the bare
+//! `impl Ca for A` / `B: Cb`. Without the marker they would name the original traits, and
+//! `Cb for B`'s ranked `A: Ca` bound would have no ranked impl to satisfy it. A path into
+//! another module (`u8: crate::other::Ca`) names that module's trait, and is kept.
+#[decycle::decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+    }
+    #[decycle]
+    pub trait Cb {
+        fn cb(&self, n: usize) -> usize;
+    }
+    pub struct A;
+    pub struct B;
+
+    #[decycle(ranked)]
+    impl crate::m::Ca for A
+    where
+        B: super::m::Cb,
+        u8: crate::other::Ca,
+    {
+        fn ca(&self, n: usize) -> usize {
+            if n == 0 {
+                crate::other::Ca::ca(&0u8, 0)
+            } else {
+                B.cb(n - 1) + 1
+            }
+        }
+    }
+
+    impl Cb for B
+    where
+        A: Ca,
+    {
+        fn cb(&self, n: usize) -> usize {
+            A.ca(n) + 1
+        }
+    }
+}
+
+pub mod other {
+    pub trait Ca {
+        fn ca(&self, n: usize) -> usize;
+    }
+    impl Ca for u8 {
+        fn ca(&self, n: usize) -> usize {
+            n + *self as usize
+        }
+    }
+}
+
+fn main() {
+    use m::Ca;
+    assert_eq!(m::A.ca(30), 60);
+}
@@ -0,0 +1,40 @@
+//! A qualified reference to a #[decycle] trait inside a `#[decycle]` module is NOT ranked —
+//! it names the original trait — so without `#[decycle(ranked)]` or `#[decycle(unranked)]`
+//! on the impl it is linted (as a `deprecated` warning, so it shows on stable; denied here).
+//! A path into another module names that module's trait, and is not linted.
+#![deny(deprecated)]
+
+#[decycle::decycle]
+mod m {
+    #[decycle]
+    pub trait Ca {
+        fn ca(&self) -> usize;
+    }
+    pub struct A;
+    pub struct B;
+
+    impl Ca for A
+    where
+        B: crate::m::Ca,
+    {
+        fn ca(&self) -> usize {
+            0
+        }
+    }
+
+    impl self::Ca for B
+    where
+        u8: crate::other::Ca,
+    {
+        fn ca(&self) -> usize {
+            1
+        }
+    }
+}
+
+pub mod other {
+    pub trait Ca {}
+    impl Ca for u8 {}
+}
+
+fn main() {}
@@ -5,12 +5,17 @@
 //! defect (D0–D7) it regresses. In particular: any width at any `recurse_level >= 1`, generic
 //! methods keyed per instantiation past the floor, phantom method generics (D6), and elided
 //! ref-returning methods (D7).
+//!
+//! After the defect sections come the re-entry shapes added since, one wrapper mod each.
 
 // The cycle's non-entry traits are only called through their ranked variants after expansion.
 #![allow(dead_code)]
 
 use decycle::decycle;
 
+mod common;
+use common::panic_msg;
+
 // ---------------------------------------------------------------------------------------------
 // D0: any floor crossing at all. These are the crate's headline feature at default settings.
 // ---------------------------------------------------------------------------------------------
@@ -554,11 +559,85 @@ fn elided_ref_return_past_floor() {
     assert_eq!(elided_m::SA.pick2("unused", 51), "floor-b");
 }
 
-fn panic_msg(e: Box<dyn std::any::Any + Send>) -> String {
-    e.downcast_ref::<String>()
-        .cloned()
-        .or_else(|| e.downcast_ref::<&str>().map(|s| s.to_string()))
-        .unwrap_or_default()
+// Elision is exactly the language's: the receiver rule only when the receiver borrows `Self`,
+// otherwise a single input lifetime — counting neither `fn(&str) -> &str` nor `Fn(&str) ->
+// &str`, whose elided lifetimes are their own. Naming those used to turn a higher-ranked fn
+// pointer into a one-lifetime one (E0308), and counting them aborted `first` as ambiguous.
+
+#[decycle(recurse_level = 1)]
+mod elision_m {
+    #[decycle]
+    pub trait Ea {
+        fn first(s: &str, f: fn(&str) -> &str, n: usize) -> &str;
+        fn tail(self: Box<Self>, s: &str, n: usize) -> &str;
+        fn apply(&self, f: &dyn Fn(&str) -> &str, n: usize) -> &str;
+    }
+    #[decycle]
+    pub trait Eb {
+        fn first(s: &str, f: fn(&str) -> &str, n: usize) -> &str;
+        fn tail(self: Box<Self>, s: &str, n: usize) -> &str;
+        fn apply(&self, f: &dyn Fn(&str) -> &str, n: usize) -> &str;
+    }
+    pub struct A(pub String);
+    pub struct B(pub String);
+    impl Ea for A
+    where
+        B: Eb,
+    {
+        fn first(s: &str, f: fn(&str) -> &str, n: usize) -> &str {
+            if n == 0 {
+                f(s)
+            } else {
+                <B as Eb>::first(&s[1..], f, n - 1)
+            }
+        }
+        fn tail(self: Box<Self>, s: &str, n: usize) -> &str {
+            if n == 0 {
+                s
+            } else {
+                Box::new(B(self.0)).tail(&s[1..], n - 1)
+            }
+        }
+        fn apply(&self, f: &dyn Fn(&str) -> &str, n: usize) -> &str {
+            if n > 0 {
+                B(self.0.clone()).apply(f, n - 1);
+            }
+            f(&self.0)
+        }
+    }
+    impl Eb for B
+    where
+        A: Ea,
+    {
+        fn first(s: &str, f: fn(&str) -> &str, n: usize) -> &str {
+            if n == 0 {
+                f(s)
+            } else {
+                <A as Ea>::first(&s[1..], f, n - 1)
+            }
+        }
+        fn tail(self: Box<Self>, s: &str, n: usize) -> &str {
+            if n == 0 {
+                s
+            } else {
+                Box::new(A(self.0)).tail(&s[1..], n - 1)
+            }
+        }
+        fn apply(&self, f: &dyn Fn(&str) -> &str, n: usize) -> &str {
+            if n > 0 {
+                A(self.0.clone()).apply(f, n - 1);
+            }
+            f(&self.0)
+        }
+    }
+}
+
+#[test]
+fn language_elision_past_floor() {
+    use elision_m::{Ea, A};
+    assert_eq!(<A as Ea>::first("abcdefgh", |s| &s[..1], 5), "f");
+    assert_eq!(Box::new(A(String::new())).tail("abcdefgh", 6), "gh");
+    assert_eq!(A("xyz".into()).apply(&|s| &s[1..], 5), "yz");
 }
 
 // ---------------------------------------------------------------------------------------------
@@ -1145,3 +1224,281 @@ fn assoc_carrying_trait_plain_recursion_past_floor_and_outside_assoc_call() {
     let out: usize = assoc_carrying_m::A.make();
     assert_eq!(out, 1);
 }
+
+/// Polymorphic recursion: `impl<T> Ca for Nest<T> where Nest<Vec<T>>: Ca` reaches a new type at
+/// every step. With `#[decycle(erase)]`, its floor re-enters through `Erase` at the one type
+/// `Nest<Rc<dyn Size>>`, so the recursion goes on to any depth in unbounded mode. Without the
+/// marker (`Cb`), the floor has no re-entry to find and fails closed. Run in both modes.
+mod polymorphic_recursion {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    macro_rules! polymorphic_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                use std::rc::Rc;
+
+                #[decycle]
+                pub trait Ca {
+                    fn ca(&self, n: usize) -> usize;
+
+                    fn ca_owned(self, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    fn cb(&self, n: usize) -> usize;
+                }
+
+                pub trait Size {
+                    fn size(&self) -> usize;
+                }
+
+                impl Size for u8 {
+                    fn size(&self) -> usize {
+                        *self as usize
+                    }
+                }
+
+                impl<T: Size> Size for Vec<T> {
+                    fn size(&self) -> usize {
+                        self.iter().map(Size::size).sum()
+                    }
+                }
+
+                impl Size for Rc<dyn Size> {
+                    fn size(&self) -> usize {
+                        (**self).size()
+                    }
+                }
+
+                pub struct Nest<T>(pub T);
+
+                #[decycle(erase)]
+                impl<T: Size + Clone + 'static> Ca for Nest<T>
+                where
+                    Nest<Vec<T>>: Ca,
+                {
+                    fn ca(&self, n: usize) -> usize {
+                        if n == 0 {
+                            self.0.size()
+                        } else {
+                            Ca::ca(&Nest(vec![self.0.clone()]), n - 1) + 1
+                        }
+                    }
+
+                    fn ca_owned(self, n: usize) -> usize {
+                        if n == 0 {
+                            self.0.size()
+                        } else {
+                            Ca::ca_owned(Nest(vec![self.0]), n - 1) + 1
+                        }
+                    }
+                }
+
+                impl<T: Size + Clone + 'static> decycle::Erase for Nest<T> {
+                    type Erased = Nest<Rc<dyn Size>>;
+
+                    fn erase(&self) -> Self::Erased {
+                        Nest(Rc::new(self.0.clone()))
+                    }
+                }
+
+                impl<T: Size + Clone> Cb for Nest<T>
+                where
+                    Nest<Vec<T>>: Cb,
+                {
+                    fn cb(&self, n: usize) -> usize {
+                        if n == 0 {
+                            self.0.size()
+                        } else {
+                            Cb::cb(&Nest(vec![self.0.clone(), self.0.clone()]), n - 1)
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    polymorphic_module!(decycle(recurse_level = 3), unbounded);
+    polymorphic_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn polymorphic_recursion_unbounded() {
+        use unbounded::{Ca, Cb, Nest};
+        assert_eq!(Nest(5u8).ca(0), 5);
+        assert_eq!(Nest(5u8).ca(2), 7);
+        assert_eq!(Nest(5u8).ca(1000), 1005);
+        assert_eq!(Nest(5u8).ca_owned(1000), 1005);
+        assert_eq!(Nest(3u8).cb(2), 12);
+        // `Cb` isn't erased: at the floor there is no re-entry registered for `Nest<Vec<..>>`.
+        let e = std::panic::catch_unwind(|| Nest(3u8).cb(3)).unwrap_err();
+        assert!(panic_msg(e).contains("re-entry fn not registered"));
+    }
+
+    #[test]
+    fn polymorphic_recursion_bounded() {
+        use bounded::{Ca, Cb, Nest};
+        assert_eq!(Nest(5u8).ca(9), 14);
+        assert_eq!(Nest(3u8).cb(3), 24);
+        // Bounded mode keeps its limit, erased or not.
+        let e = std::panic::catch_unwind(|| Nest(5u8).ca(100)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}
+
+/// `#[decycle(erase(S => dyn Stream))]` on a generic trait method: its floor re-enters at
+/// `S = &mut dyn Stream` (`&dyn Peek` for a `&P` parameter) whatever `S` it was reached with,
+/// so a stream that grows a layer per step (`Dup<S>`) recurses to any depth in unbounded mode
+/// and the re-entry is registered on the first descent. The signatures stay generic: the
+/// `&mut dyn` re-borrow happens at the floor only. Run in both modes.
+mod erased_streams {
+    use decycle::decycle;
+    use crate::common::panic_msg;
+
+    pub trait Stream {
+        fn advance(&mut self) -> Option<u8>;
+    }
+
+    impl<S: Stream + ?Sized> Stream for &mut S {
+        fn advance(&mut self) -> Option<u8> {
+            (**self).advance()
+        }
+    }
+
+    pub trait Peek {
+        fn peek(&self) -> u8;
+    }
+
+    impl<P: Peek + ?Sized> Peek for &P {
+        fn peek(&self) -> u8 {
+            (**self).peek()
+        }
+    }
+
+    /// A stream over another, one more type layer per step of the cycle.
+    pub struct Dup<'a, S: ?Sized>(pub &'a mut S);
+
+    impl<S: Stream + ?Sized> Stream for Dup<'_, S> {
+        fn advance(&mut self) -> Option<u8> {
+            self.0.advance()
+        }
+    }
+
+    pub struct Count(pub usize);
+
+    impl Stream for Count {
+        fn advance(&mut self) -> Option<u8> {
+            self.0 += 1;
+            None
+        }
+    }
+
+    impl Peek for Count {
+        fn peek(&self) -> u8 {
+            self.0 as u8
+        }
+    }
+
+    macro_rules! erased_module {
+        ($decycle_attr:meta, $mod_name:ident) => {
+            #[$decycle_attr]
+            mod $mod_name {
+                use super::{Dup, Peek, Stream};
+
+                #[decycle]
+                pub trait Ca {
+                    #[decycle(erase(S => dyn Stream))]
+                    fn ca<S: Stream>(&self, stream: &mut S, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cb {
+                    #[decycle(erase(S => dyn Stream))]
+                    fn cb<S: Stream>(&self, stream: &mut S, n: usize) -> usize;
+                }
+
+                #[decycle]
+                pub trait Cc {
+                    #[decycle(erase(S => dyn Stream, P => dyn Peek))]
+                    fn cc<S: Stream, P: Peek>(&self, stream: &mut S, peek: &P, n: usize) -> usize;
+                }
+
+                pub struct A;
+                pub struct B;
+                pub struct C<T>(pub T);
+
+                impl Ca for A
+                where
+                    B: Cb,
+                {
+                    fn ca<S: Stream>(&self, stream: &mut S, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            stream.advance();
+                            B.cb(stream, n - 1) + 1
+                        }
+                    }
+                }
+
+                impl Cb for B
+                where
+                    C<u8>: Cc,
+                {
+                    fn cb<S: Stream>(&self, stream: &mut S, n: usize) -> usize {
+                        if n == 0 {
+                            0
+                        } else {
+                            let count = crate::erased_streams::Count(3);
+                            C(1u8).cc(&mut Dup(stream), &count, n - 1) + 10
+                        }
+                    }
+                }
+
+                impl<T: Copy + Into<usize>> Cc for C<T>
+                where
+                    A: Ca,
+                {
+                    fn cc<S: Stream, P: Peek>(&self, stream: &mut S, peek: &P, n: usize) -> usize {
+                        if n == 0 {
+                            peek.peek() as usize
+                        } else {
+                            A.ca(&mut Dup(stream), n - 1) + self.0.into() * 100
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    erased_module!(decycle(recurse_level = 1), unbounded);
+    erased_module!(decycle(support_infinite_cycle = false), bounded);
+
+    #[test]
+    fn erased_streams_unbounded() {
+        use unbounded::{Ca, Cc};
+        let mut count = Count(0);
+        assert_eq!(unbounded::A.ca(&mut count, 2), 14);
+        assert_eq!(count.0, 1);
+        let mut count = Count(0);
+        assert_eq!(unbounded::A.ca(&mut count, 3), 111);
+        let mut count = Count(0);
+        assert_eq!(unbounded::A.ca(&mut count, 3000), 111000);
+        assert_eq!(count.0, 1000);
+        assert_eq!(unbounded::C(2u8).cc(&mut Count(0), &Count(7), 0), 7);
+        assert_eq!(unbounded::C(2u8).cc(&mut Count(0), &Count(7), 4), 311);
+    }
+
+    #[test]
+    fn erased_streams_bounded() {
+        use bounded::Ca;
+        let mut count = Count(0);
+        assert_eq!(bounded::A.ca(&mut count, 6), 222);
+        assert_eq!(count.0, 2);
+        // Bounded mode keeps its limit: erasing only changes how the floor re-enters.
+        let e = std::panic::catch_unwind(|| bounded::A.ca(&mut Count(0), 100)).unwrap_err();
+        assert!(panic_msg(e).contains("decycle: cycle limit reached"));
+    }
+}